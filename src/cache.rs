@@ -0,0 +1,186 @@
+//! In-memory response caches, used by the client to make conditional
+//! requests with `If-None-Match`, to memoize reference data for a while and
+//! to avoid repeating creates.
+
+// ============================================================================
+// Use
+// ============================================================================
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+/// A body previously returned by JIRA along with the ETag it was served with
+#[derive(Clone, Debug)]
+pub(crate) struct CachedResponse {
+    pub etag: HeaderValue,
+    pub body: String,
+}
+
+/// Clones of the cache share the same entries, this means a client cloned to
+/// add headers or query arguments still benefits from earlier responses.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EtagCache {
+    entries: Arc<Mutex<HashMap<String, CachedResponse>>>,
+}
+
+impl EtagCache {
+    /// Returns the cached response for a URL if there is one
+    pub fn get(&self, url: &str) -> Option<CachedResponse> {
+        self.entries
+            .lock()
+            .ok()
+            .and_then(|e| e.get(url).cloned())
+    }
+
+    /// Stores a response for a URL replacing any previous entry
+    pub fn insert(&self, url: String, etag: HeaderValue, body: String) {
+        if let Ok(mut e) = self.entries.lock() {
+            e.insert(url, CachedResponse { etag, body });
+        }
+    }
+
+    /// Removes every cached response
+    pub fn clear(&self) {
+        if let Ok(mut e) = self.entries.lock() {
+            e.clear();
+        }
+    }
+}
+
+/// A successful GET response along with when it was stored
+#[derive(Clone, Debug)]
+pub(crate) struct TimedResponse {
+    pub stored: Instant,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// Successful GET responses keyed by URL, including the query string, that
+/// are served until they are older than the TTL. Like the ETag cache clones
+/// share the same entries.
+#[derive(Clone, Debug)]
+pub(crate) struct TtlCache {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<String, TimedResponse>>>,
+}
+
+impl TtlCache {
+    pub fn new(ttl: Duration) -> Self {
+        TtlCache {
+            ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the response for a URL if it is younger than the TTL, expired
+    /// entries are dropped
+    pub fn get(&self, url: &str) -> Option<TimedResponse> {
+        let mut e = self.entries.lock().ok()?;
+
+        match e.get(url) {
+            Some(r) if r.stored.elapsed() < self.ttl => Some(r.clone()),
+            Some(_) => {
+                e.remove(url);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores a response for a URL replacing any previous entry
+    pub fn insert(&self, url: String, headers: HeaderMap, body: String) {
+        if let Ok(mut e) = self.entries.lock() {
+            let stored = Instant::now();
+            e.insert(url, TimedResponse { stored, headers, body });
+        }
+    }
+
+    /// Removes every cached response
+    pub fn clear(&self) {
+        if let Ok(mut e) = self.entries.lock() {
+            e.clear();
+        }
+    }
+}
+
+/// A successful response to a create, kept so that repeating the create with
+/// the same idempotency key returns it instead of creating a duplicate
+#[derive(Clone, Debug)]
+pub(crate) struct CreatedResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// Responses to creates keyed by the method, URL, a hash of the body and the
+/// caller supplied idempotency key, like the ETag cache clones share the same entries.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct IdempotencyCache {
+    entries: Arc<Mutex<HashMap<String, CreatedResponse>>>,
+}
+
+impl IdempotencyCache {
+    /// Returns the response stored against a key if there is one
+    pub fn get(&self, key: &str) -> Option<CreatedResponse> {
+        self.entries
+            .lock()
+            .ok()
+            .and_then(|e| e.get(key).cloned())
+    }
+
+    /// Stores the response to a create against a key
+    pub fn insert(&self, key: String, response: CreatedResponse) {
+        if let Ok(mut e) = self.entries.lock() {
+            e.insert(key, response);
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_entries() {
+        let cache = EtagCache::default();
+        let other = cache.clone();
+
+        other.insert(
+            "http://jira/rest/api/2/status".to_string(),
+            HeaderValue::from_static("\"abc\""),
+            "[]".to_string(),
+        );
+
+        let cached = cache.get("http://jira/rest/api/2/status").unwrap();
+        assert_eq!(cached.etag, "\"abc\"");
+        assert_eq!(cached.body, "[]");
+
+        cache.clear();
+        assert!(other.get("http://jira/rest/api/2/status").is_none());
+    }
+
+    #[test]
+    fn test_ttl_expires_entries() {
+        let fresh = TtlCache::new(Duration::from_secs(60));
+        let stale = TtlCache::new(Duration::from_secs(0));
+
+        for cache in &[&fresh, &stale] {
+            cache.insert(
+                "http://jira/rest/api/2/priority".to_string(),
+                HeaderMap::new(),
+                "[]".to_string(),
+            );
+        }
+
+        assert_eq!(fresh.get("http://jira/rest/api/2/priority").unwrap().body, "[]");
+        assert!(stale.get("http://jira/rest/api/2/priority").is_none());
+    }
+}
@@ -3,16 +3,87 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::cache::{CachedResponse, CreatedResponse, EtagCache, IdempotencyCache, TtlCache};
+use crate::fixture::{self, Recorder, Replayer};
+use crate::jwt;
 use crate::Credentials;
 use crate::Options;
+use crate::Query;
+use crate::Precondition;
 use crate::Resp;
 use crate::Response;
-use crate::Serialize;
+use crate::Result;
+use crate::RetryPolicy;
+use crate::{Deserialize, Serialize};
+use crate::rate_limit::RateLimit;
+use crate::trace::RequestTrace;
+use crate::transport::{Direct, Network, Reply, Transport};
+use crate::v2::{ServerInfo, User};
 use crate::{Error, Errors};
-use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
-use reqwest::{Method, StatusCode};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::header::{
+    HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, COOKIE, ETAG, IF_NONE_MATCH,
+};
+use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub use reqwest::{Certificate, Identity, Method};
+
+// ============================================================================
+// Public Enums
+// ============================================================================
+/// The REST APIs exposed by JIRA, each lives under its own root and is
+/// versioned separately from the others.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Api {
+    /// The platform API, `rest/api/2`
+    V2,
+
+    /// The platform API using the Atlassian Document Format for rich text,
+    /// `rest/api/3`. Only available on JIRA Cloud.
+    V3,
+
+    /// The authentication API used for session cookies, `rest/auth/1`
+    Auth,
+
+    /// The JIRA Software API for boards and sprints, `rest/agile/1.0`
+    Agile,
+}
+
+impl Api {
+    /// Returns the group the API lives under e.g. "api" or "agile"
+    pub fn group(self) -> &'static str {
+        match self {
+            Api::V2 | Api::V3 => "api",
+            Api::Agile => "agile",
+            Api::Auth => "auth",
+        }
+    }
+
+    /// Returns the version of the API e.g. "2" or "1.0"
+    pub fn version(self) -> &'static str {
+        match self {
+            Api::V2 => "2",
+            Api::V3 => "3",
+            Api::Agile => "1.0",
+            Api::Auth => "1",
+        }
+    }
+
+    /// Returns the endpoint for a resource within the API, this is relative
+    /// to `rest/` on the host and can be given to the request functions on
+    /// [`Client`]. Endpoints built with `format!` can use the `Display`
+    /// implementation instead e.g. `format!("{}/issue/{}", Api::V2, key)`.
+    pub fn endpoint(self, resource: &str) -> String {
+        format!("{}/{}/{}", self.group(), self.version(), resource)
+    }
+}
 
 // ============================================================================
 // Public Structures
@@ -25,10 +96,170 @@ pub struct Client {
     pub client: reqwest::Client,
     pub credentials: Credentials,
     pub headers: HeaderMap,
-    pub query: HashMap<String, String>,
+    pub query: Query,
+    cache: Option<EtagCache>,
+    ttl_cache: Option<TtlCache>,
+    use_cache: bool,
+    max_response_bytes: Option<u64>,
+    retry: Option<RetryPolicy>,
+    retry_safe: bool,
+    idempotency_key: Option<String>,
+    created: IdempotencyCache,
+    method_override: bool,
+    transport: Arc<dyn Transport>,
+}
+
+/// The outcome of `Client::check` for an instance that could be reached
+#[derive(Debug)]
+pub struct HealthCheck {
+    /// Details of the instance, None if JIRA refused to share them with the
+    /// credentials of the client
+    pub server: Option<ServerInfo>,
+
+    /// The user the client is authenticated as, None if the credentials were
+    /// rejected
+    pub user: Option<User>,
+}
+
+impl HealthCheck {
+    /// Returns true if the credentials of the client were accepted
+    pub fn is_authenticated(&self) -> bool {
+        self.user.is_some()
+    }
+}
+
+/// Builds a client with custom transport settings, e.g. TLS options for
+/// instances using a private certificate authority
+pub struct ClientBuilder {
+    host: String,
+    credentials: Credentials,
+    builder: reqwest::ClientBuilder,
+    max_response_bytes: Option<u64>,
+    method_override: bool,
+    transport: Option<Arc<dyn Transport>>,
+}
+
+impl ClientBuilder {
+    /// Trusts an additional root certificate on top of the system trust
+    /// store, a certificate can be read from PEM with `Certificate::from_pem`
+    pub fn add_root_certificate(mut self, cert: Certificate) -> Self {
+        self.builder = self.builder.add_root_certificate(cert);
+        self
+    }
+
+    /// Presents a client certificate to the server, an identity can be read
+    /// from a PKCS #12 archive with `Identity::from_pkcs12_der`
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.builder = self.builder.identity(identity);
+        self
+    }
+
+    /// Disables certificate verification entirely, any certificate presented
+    /// by the server is trusted including expired ones and ones for another
+    /// host. Only use this during development.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.builder = self.builder.danger_accept_invalid_certs(accept);
+        self
+    }
+
+    /// Limits how many idle connections are kept open to a host for reuse.
+    /// The HTTP backend does not support configuring how long an idle
+    /// connection is kept, idle connections are closed after 90 seconds.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.builder = self.builder.max_idle_per_host(max);
+        self
+    }
+
+    /// Talks HTTP/2 to the server without negotiating it first, only use
+    /// this when you know the server or proxy in front of JIRA supports it
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.builder = self.builder.h2_prior_knowledge();
+        self
+    }
+
+    /// Caps the size of response bodies, reading stops as soon as a body
+    /// grows past the limit and `Error::ResponseTooLarge` is returned. There
+    /// is no limit by default.
+    pub fn max_response_bytes(mut self, max: u64) -> Self {
+        self.max_response_bytes = Some(max);
+        self
+    }
+
+    /// Tunnels PUT, PATCH and DELETE requests through POST, naming the real
+    /// method in the `X-HTTP-Method-Override` header, for gateways that only
+    /// let GET and POST through. JIRA honours the header but proxies in front
+    /// of it may not, so this is off by default.
+    pub fn method_override(mut self) -> Self {
+        self.method_override = true;
+        self
+    }
+
+    /// Writes every request and response to a directory as JSON fixtures
+    /// that `replay_fixtures` can serve later. Credentials, session cookies
+    /// and secrets in the query are scrubbed before writing.
+    pub fn record_fixtures<P>(mut self, dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.transport = Some(Arc::new(Recorder(dir.into())));
+        self
+    }
+
+    /// Answers requests from fixtures written by `record_fixtures` instead
+    /// of the network. A request that wasn't recorded fails with
+    /// `Error::FixtureNotFound`.
+    pub fn replay_fixtures<P>(mut self, dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.transport = Some(Arc::new(Replayer(dir.into())));
+        self
+    }
+
+    /// Records fixtures to the directory in `JIRAC_RECORD`, or replays them
+    /// from the one in `JIRAC_REPLAY`, so a test suite can switch between
+    /// the two without code changes. If both are set recording wins, if
+    /// neither is the client talks to the network as usual.
+    pub fn fixtures_from_env(mut self) -> Self {
+        if let Some(transport) = fixture::from_env() {
+            self.transport = Some(transport);
+        }
+        self
+    }
+
+    /// Creates the client, this fails if the TLS backend can't be initialised
+    /// with the given settings
+    pub fn build(self) -> Result<Client> {
+        let mut c = Client::new(self.host, self.credentials);
+
+        c.client = self.builder.build()?;
+        c.max_response_bytes = self.max_response_bytes;
+        c.method_override = self.method_override;
+
+        if let Some(transport) = self.transport {
+            c.transport = transport;
+        }
+
+        Ok(c)
+    }
 }
 
 impl Client {
+    /// Returns a builder for a client that requires custom transport settings
+    pub fn builder<H>(host: H, credentials: Credentials) -> ClientBuilder
+    where
+        H: Into<String>,
+    {
+        ClientBuilder {
+            host: host.into(),
+            credentials,
+            builder: reqwest::Client::builder(),
+            max_response_bytes: None,
+            method_override: false,
+            transport: None,
+        }
+    }
+
     /// Creates a new instance of the JIRA client
     pub fn new<H>(host: H, credentials: Credentials) -> Client
     where
@@ -39,8 +270,179 @@ impl Client {
             client: reqwest::Client::new(),
             credentials,
             headers: HeaderMap::new(),
-            query: HashMap::new(),
+            query: Query::new(),
+            cache: None,
+            ttl_cache: None,
+            use_cache: true,
+            max_response_bytes: None,
+            retry: None,
+            retry_safe: false,
+            idempotency_key: None,
+            created: IdempotencyCache::default(),
+            method_override: false,
+            transport: Arc::new(Direct),
+        }
+    }
+
+    /// Logs in with a username and password and returns a client that
+    /// authenticates with the session cookie JIRA hands back, for instances
+    /// that only accept cookie based sessions. Once the session expires
+    /// requests fail with `Error::SessionExpired` and you need to log in
+    /// again. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#auth/1/session-login
+    pub fn login<H, U, P>(host: H, username: U, password: P) -> Result<Client>
+    where
+        H: Into<String>,
+        U: Into<String>,
+        P: Into<String>,
+    {
+        #[derive(Serialize)]
+        struct Request {
+            username: String,
+            password: String,
         }
+
+        #[derive(Deserialize)]
+        struct Session {
+            name: String,
+            value: String,
+        }
+
+        #[derive(Deserialize)]
+        struct Login {
+            session: Session,
+        }
+
+        let body = Request {
+            username: username.into(),
+            password: password.into(),
+        };
+        // No cookie is sent until the session has been created
+        let c = Client::new(host, Credentials::Session(String::new(), String::new()));
+        let Resp { data, .. } = c.post::<_, Login>(&Api::Auth.endpoint("session"), body)?;
+
+        Ok(Client {
+            credentials: Credentials::Session(data.session.name, data.session.value),
+            ..c
+        })
+    }
+
+    /// Ends the current session, only useful for clients created with
+    /// `Client::login` or session credentials. For more info consult the api
+    /// docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#auth/1/session-logout
+    pub fn logout(&self) -> Response<()> {
+        self.delete(&Api::Auth.endpoint("session"))
+    }
+
+    /// Checks the host can be reached and the credentials are accepted using
+    /// `serverInfo` and `myself`, suitable for readiness probes. An instance
+    /// that can't be reached gives the transport error, e.g.
+    /// `Error::Connect` or `Error::Timeout`, while rejected credentials give
+    /// a `HealthCheck` that isn't authenticated. Caches are bypassed.
+    pub fn check(&self) -> Result<HealthCheck> {
+        let c = self.clone().without_cache();
+        let rejected = |e: &Error| {
+            matches!(e, Error::Unauthorized | Error::SessionExpired | Error::Forbidden)
+        };
+
+        let server = match ServerInfo::get(&c) {
+            Ok(r) => Some(r.data),
+            Err(ref e) if rejected(e) => None,
+            Err(e) => return Err(e),
+        };
+        let user = match User::myself(&c) {
+            Ok(r) => Some(r.data),
+            Err(ref e) if rejected(e) => None,
+            Err(e) => return Err(e),
+        };
+
+        Ok(HealthCheck { server, user })
+    }
+
+    /// Enables an in-memory ETag cache, GET requests then send the last seen
+    /// ETag in `If-None-Match` and when JIRA answers with a 304 the cached
+    /// body is returned along with the new headers. Clones of the client share
+    /// the cache.
+    pub fn with_etag_cache(mut self) -> Self {
+        self.cache = Some(EtagCache::default());
+        self
+    }
+
+    /// Removes every response held in the ETag cache
+    pub fn clear_etag_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Enables an in-memory cache of successful GET responses, a response is
+    /// returned from the cache without contacting JIRA until it is older than
+    /// `ttl`. Entries are keyed by the URL including the query string. Meant
+    /// for reference data such as priorities that rarely changes, clones of
+    /// the client share the cache and it is safe to use across threads.
+    pub fn with_cache(mut self, ttl: Duration) -> Self {
+        self.ttl_cache = Some(TtlCache::new(ttl));
+        self
+    }
+
+    /// Removes every response held in the TTL cache
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.ttl_cache {
+            cache.clear();
+        }
+    }
+
+    /// Bypasses the ETag and TTL caches for requests sent with the returned
+    /// client, useful when you need to be sure the data is fresh.
+    pub fn without_cache(mut self) -> Self {
+        self.use_cache = false;
+        self
+    }
+
+    /// Retries requests that fail because of a transport error or a 429, 502,
+    /// 503 or 504 response. POST and PATCH requests are never retried unless
+    /// the client they are sent with is marked with `retry_safe`.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Marks requests sent with the returned client as safe to repeat so the
+    /// retry policy also applies to POST and PATCH, only use this when
+    /// sending the request twice can't create a duplicate.
+    pub fn retry_safe(mut self) -> Self {
+        self.retry_safe = true;
+        self
+    }
+
+    /// Deduplicates creates sent with the returned client. The first
+    /// successful response to a create for a key is kept for the lifetime of
+    /// the client (and its clones) and sending the same create, to the same
+    /// endpoint with the same query and body, with the same key returns that
+    /// response without contacting JIRA. Everything else, including POSTs
+    /// that only read such as searches, is sent as usual.
+    pub fn idempotency_key<K>(mut self, key: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Sends the If-Match and If-None-Match headers of the precondition with
+    /// requests made by the returned client, a None leaves the client as is.
+    pub fn precondition(mut self, p: Option<Precondition>) -> Self {
+        if let Some(p) = p {
+            if let Some(hash) = p.if_match {
+                self.add_header("if-match", hash);
+            }
+            if let Some(hash) = p.if_none_match {
+                self.add_header("if-none-match", hash);
+            }
+        }
+
+        self
     }
 
     /// Add request headers before sending your request
@@ -62,7 +464,7 @@ impl Client {
     }
 
     /// Add query string arguments before sending your request
-    pub fn add_query(mut self, query: HashMap<String, String>) -> Self {
+    pub fn add_query(mut self, query: Query) -> Self {
         let mut q = self.query;
         q.extend(query);
         self.query = q;
@@ -70,20 +472,21 @@ impl Client {
         self
     }
 
-    /// Unpacks options into a HashMap<String, String> allowing them to be easily
-    /// represented in a query string.
-    pub fn unpack_options(opts: Vec<&dyn Options>) -> HashMap<String, String> {
-        opts.iter().fold(HashMap::new(), |acc, o| {
+    /// Unpacks options into a Query allowing them to be easily represented in
+    /// a query string.
+    pub fn unpack_options(opts: Vec<&dyn Options>) -> Query {
+        opts.iter().fold(Query::new(), |acc, o| {
             let mut h = acc;
             h.extend(o.to_query());
             h
         })
     }
 
-    /// Unpacks a HashMap<String, String> into a query string.
-    pub fn unpack_query(query: &HashMap<String, String>) -> String {
+    /// Unpacks a Query into a query string, keys and values are
+    /// percent-encoded.
+    pub fn unpack_query(query: &Query) -> String {
         let mut ret = query.iter().fold(String::from("?"), |acc, (k, v)| {
-            format!("{}{}={}&", acc, k, v)
+            format!("{}{}={}&", acc, encode_component(k), encode_component(v))
         });
         ret.pop();
         ret
@@ -109,6 +512,16 @@ impl Client {
         self.request::<D>(Method::POST, url, Some(data.into_bytes()))
     }
 
+    pub fn patch<S, D>(&self, url: &str, body: S) -> Response<D>
+    where
+        D: DeserializeOwned,
+        S: Serialize,
+    {
+        let data = serde_json::to_string::<S>(&body)?;
+
+        self.request::<D>(Method::PATCH, url, Some(data.into_bytes()))
+    }
+
     pub fn get<D>(&self, url: &str) -> Response<D>
     where
         D: DeserializeOwned,
@@ -116,44 +529,415 @@ impl Client {
         self.request::<D>(Method::GET, url, None)
     }
 
+    pub fn delete<D>(&self, url: &str) -> Response<D>
+    where
+        D: DeserializeOwned,
+    {
+        self.request::<D>(Method::DELETE, url, None)
+    }
+
+    /// Sends a request to an endpoint this library doesn't model yet and
+    /// returns the untyped JSON response, authentication, headers, retries
+    /// and error handling are the same as for every other request. The
+    /// endpoint is relative to `rest/` e.g. `Api::V2.endpoint("serverInfo")`
+    /// and `query` is merged into any query already set on the client.
+    pub fn request_raw(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: Query,
+        body: Option<serde_json::Value>,
+    ) -> Response<serde_json::Value> {
+        let body = match body {
+            Some(b) => Some(serde_json::to_vec(&b)?),
+            None => None,
+        };
+
+        self.clone().add_query(query).request(method, endpoint, body)
+    }
+
+    /// Sends a request to an endpoint relative to `rest/` on the host and
+    /// returns the body as is rather than deserializing it, for endpoints
+    /// that don't answer with JSON. Error responses are handled as for every
+    /// other request, the caches are not used.
+    pub fn request_bytes(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<Vec<u8>>,
+    ) -> Response<Vec<u8>> {
+        let query = Client::unpack_query(&self.query);
+        let url = format!("{}/rest/{}{}", self.host, url, query);
+
+        self.fetch_bytes(method, &url, body)
+    }
+
+    /// Sends a request to an endpoint relative to `rest/` on the host and
+    /// returns the body as text, see `request_bytes`
+    pub fn request_text(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<Vec<u8>>,
+    ) -> Response<String> {
+        let Resp { data, headers } = self.request_bytes(method, url, body)?;
+
+        Ok(Resp {
+            data: String::from_utf8_lossy(&data).into_owned(),
+            headers,
+        })
+    }
+
+    /// Downloads content JIRA links to outside of the REST API, such as the
+    /// `content` of an attachment, given its absolute URL. Credentials are
+    /// only sent to the host of the client, a URL elsewhere fails with
+    /// `Error::ForeignUrl`.
+    pub fn download(&self, url: &str) -> Response<Vec<u8>> {
+        let host = self.host.trim_end_matches('/');
+        let on_host = url.starts_with(host)
+            && matches!(url[host.len()..].chars().next(), None | Some('/') | Some('?'));
+
+        if !on_host {
+            return Err(Error::ForeignUrl(url.to_string()));
+        }
+
+        self.fetch_bytes(Method::GET, url, None)
+    }
+
+    /// Sends a request to an absolute URL returning the raw body
+    fn fetch_bytes(&self, method: Method, url: &str, body: Option<Vec<u8>>) -> Response<Vec<u8>> {
+        let Reply {
+            status,
+            headers,
+            body,
+        } = self.send(&method, url, body, None)?;
+
+        match Client::error(status, &String::from_utf8_lossy(&body), &self.credentials) {
+            Some(e) => Err(e),
+            None => Ok(Resp {
+                data: body,
+                headers,
+            }),
+        }
+    }
+
+    /// Sends a request through the transport of the client retrying it as
+    /// the retry policy allows, an ETag from the cache is sent in
+    /// `If-None-Match`
+    fn send(
+        &self,
+        method: &Method,
+        url: &str,
+        body: Option<Vec<u8>>,
+        cached: Option<&CachedResponse>,
+    ) -> Result<Reply> {
+        let retry = match self.retry {
+            Some(p) if self.retry_safe || RetryPolicy::idempotent(method) => Some(p),
+            _ => None,
+        };
+        let net = Network {
+            client: &self.client,
+            max_response_bytes: self.max_response_bytes,
+        };
+
+        let mut attempt = 0;
+        let res = loop {
+            let req = self.build_request(method, url, body.clone(), cached)?;
+            let trace = RequestTrace::start(req.method(), url, req.headers());
+            let result = self.transport.execute(&net, req, body.as_deref());
+
+            let delay = match (&result, retry) {
+                (_, Some(p)) if attempt >= p.max_retries => None,
+                (Ok(res), Some(p)) if RetryPolicy::retryable_status(res.status) => {
+                    let retry_after =
+                        RateLimit::from_headers(&res.headers).and_then(|r| r.retry_after);
+                    Some(p.delay(attempt, retry_after))
+                }
+                (Err(e), Some(p)) if e.is_retryable() => Some(p.delay(attempt, None)),
+                _ => None,
+            };
+
+            match result {
+                Ok(ref res) => trace.finish(res.status),
+                Err(ref e) => trace.fail(e),
+            }
+
+            match delay {
+                Some(delay) => {
+                    attempt += 1;
+                    trace.retry(attempt, delay);
+                    thread::sleep(delay);
+                }
+                None => break result?,
+            }
+        };
+
+        Ok(res)
+    }
+
+    /// Sends a request to an endpoint relative to `rest/` on the host, build
+    /// the endpoint with [`Api`] so the API root and version aren't hardcoded.
     pub fn request<D>(&self, method: Method, url: &str, body: Option<Vec<u8>>) -> Response<D>
     where
         D: DeserializeOwned,
     {
+        self.dispatch(method, url, body, false)
+    }
+
+    /// Sends a POST that creates something, e.g. an issue or a version. With
+    /// an idempotency key set on the client repeating the same create, that
+    /// is the same endpoint, query and body, returns the first response
+    /// instead of creating a duplicate, see `idempotency_key`.
+    pub fn create<S, D>(&self, url: &str, body: S) -> Response<D>
+    where
+        D: DeserializeOwned,
+        S: Serialize,
+    {
+        let data = serde_json::to_string::<S>(&body)?;
+
+        self.dispatch::<D>(Method::POST, url, Some(data.into_bytes()), true)
+    }
+
+    /// Sends a request, `create` marks it as a create that the idempotency
+    /// key of the client applies to
+    fn dispatch<D>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<Vec<u8>>,
+        create: bool,
+    ) -> Response<D>
+    where
+        D: DeserializeOwned,
+    {
+        let endpoint = url;
         let query = Client::unpack_query(&self.query);
         let url = format!("{}/rest/{}{}", self.host, url, query);
-        let req = self.client.request(method, &url);
+
+        // Only creates are deduplicated, a key carried over to reads such as
+        // search pages or to another create must not answer them with the
+        // stored response
+        let idempotency_key = match self.idempotency_key {
+            Some(ref key) if create => {
+                let hash = Sha256::digest(body.as_deref().unwrap_or_default());
+                let hash: String = hash.iter().map(|b| format!("{:02x}", b)).collect();
+
+                Some(format!("{} {} {} {}", method, url, hash, key))
+            }
+            _ => None,
+        };
+
+        if let Some(created) = idempotency_key.as_ref().and_then(|k| self.created.get(k)) {
+            return Client::parse(endpoint, created.status, created.headers, &created.body);
+        }
+
+        let cache = match self.cache {
+            Some(ref cache) if self.use_cache && method == Method::GET => Some(cache),
+            _ => None,
+        };
+        let cached = cache.and_then(|c| c.get(&url));
+        let ttl_cache = match self.ttl_cache {
+            Some(ref cache) if self.use_cache && method == Method::GET => Some(cache),
+            _ => None,
+        };
+
+        if let Some(hit) = ttl_cache.and_then(|c| c.get(&url)) {
+            return Client::parse(endpoint, StatusCode::OK, hit.headers, &hit.body);
+        }
+
+        let res = self.send(&method, &url, body, cached.as_ref())?;
+
+        let status = res.status;
+        let body = match cached {
+            Some(cached) if status == StatusCode::NOT_MODIFIED => cached.body,
+            _ => String::from_utf8_lossy(&res.body).into_owned(),
+        };
+
+        if let (Some(cache), Some(etag)) = (cache, res.headers.get(ETAG)) {
+            if status.is_success() {
+                cache.insert(url.clone(), etag.clone(), body.clone());
+            }
+        }
+
+        if let Some(cache) = ttl_cache {
+            if status.is_success() {
+                cache.insert(url.clone(), res.headers.clone(), body.clone());
+            }
+        }
+
+        if let Some(key) = idempotency_key {
+            if status.is_success() {
+                self.created.insert(
+                    key,
+                    CreatedResponse {
+                        status,
+                        headers: res.headers.clone(),
+                        body: body.clone(),
+                    },
+                );
+            }
+        }
+
+        let headers = res.headers;
+
+        match Client::parse(endpoint, status, headers, &body) {
+            Err(Error::Unauthorized) if Client::has_session(&self.credentials) => {
+                Err(Error::SessionExpired)
+            }
+            r => r,
+        }
+    }
+
+    /// Signs a request with the Connect app credentials of the client, the
+    /// token is bound to the method, path and query of the request
+    fn connect_jwt(&self, method: &Method, url: &str) -> String {
+        let (key, shared_secret, account_id) = match self.credentials {
+            Credentials::ConnectJwt { ref key, ref shared_secret, ref account_id } => {
+                (key.as_str(), shared_secret.as_str(), account_id.as_deref())
+            }
+            _ => return String::new(),
+        };
+        let relative = url.strip_prefix(self.host.trim_end_matches('/')).unwrap_or(url);
+        let mut parts = relative.splitn(2, '?');
+        let path = parts.next().unwrap_or_default();
+        let query = parts.next().unwrap_or_default();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        jwt::token(key, shared_secret, account_id, method.as_str(), path, query, now)
+    }
+
+    /// Builds a request with the credentials and headers of the client
+    fn build_request(
+        &self,
+        method: &Method,
+        url: &str,
+        body: Option<Vec<u8>>,
+        cached: Option<&CachedResponse>,
+    ) -> Result<reqwest::Request> {
+        let tunnel = self.method_override && *method != Method::GET && *method != Method::POST;
+        let req = if tunnel {
+            self.client
+                .request(Method::POST, url)
+                .header("x-http-method-override", method.as_str())
+        } else {
+            self.client.request(method.clone(), url)
+        };
         let builder = match self.credentials {
             Credentials::Basic(ref user, ref pass) => req
                 .basic_auth(user.to_owned(), Some(pass.to_owned())),
             Credentials::OAuth(ref token) => req
-                .bearer_auth(token)
+                .bearer_auth(token),
+            Credentials::Session(ref name, ref value) if !name.is_empty() => req
+                .header(COOKIE, format!("{}={}", name, value)),
+            Credentials::Session(..) => req,
+            Credentials::ConnectJwt { .. } => {
+                let wire = if tunnel { &Method::POST } else { method };
+                req.header(AUTHORIZATION, format!("JWT {}", self.connect_jwt(wire, url)))
+            }
         }.header(CONTENT_TYPE, HeaderValue::from_static("application/json")).headers(self.headers.clone());
 
-        let mut res = match body {
-            Some(body) => builder.body(body).send()?,
-            _ => builder.send()?,
+        let builder = match cached {
+            Some(cached) => builder.header(IF_NONE_MATCH, cached.etag.clone()),
+            None => builder,
+        };
+
+        let req = match body {
+            Some(body) => builder.body(body).build()?,
+            _ => builder.build()?,
         };
 
-        let body = res.text()?;
-        let data = if body == "" { "null" } else { &body };
+        Ok(req)
+    }
+
+    /// Turns a response into either the deserialized data or an error
+    fn parse<D>(endpoint: &str, status: StatusCode, headers: HeaderMap, body: &str) -> Response<D>
+    where
+        D: DeserializeOwned,
+    {
+        let data = if body.is_empty() { "null" } else { body };
 
-        match res.status() {
-            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
-            StatusCode::METHOD_NOT_ALLOWED => Err(Error::MethodNotAllowed),
-            StatusCode::NOT_FOUND => Err(Error::NotFound),
-            StatusCode::PRECONDITION_FAILED => Err(Error::PreconditionFailed),
-            StatusCode::FORBIDDEN => Err(Error::Forbidden),
-            client_err if client_err.is_client_error() => Err(Error::Fault {
-                code: res.status(),
-                errors: serde_json::from_str::<Errors>(&body)?,
+        match Client::status_error(status, body) {
+            Some(e) => Err(e),
+            None => Ok(Resp {
+                data: Error::decode::<D>(endpoint, data)?,
+                headers,
             }),
-            _ => Ok(Resp {
-                data: serde_json::from_str::<D>(data)?,
-                headers: res.headers().clone(),
+        }
+    }
+
+    /// Returns the error for a response status, None for success
+    fn status_error(status: StatusCode, body: &str) -> Option<Error> {
+        match status {
+            StatusCode::UNAUTHORIZED => Some(Error::Unauthorized),
+            StatusCode::METHOD_NOT_ALLOWED => Some(Error::MethodNotAllowed),
+            StatusCode::NOT_FOUND => Some(Error::NotFound),
+            StatusCode::PRECONDITION_FAILED => Some(Error::PreconditionFailed),
+            StatusCode::FORBIDDEN => Some(Error::Forbidden),
+            err if err.is_client_error() || err.is_server_error() => Some(Error::Fault {
+                code: status,
+                errors: Errors::from_body(body),
             }),
+            _ => None,
         }
     }
+
+    /// Returns the error for a response status as `status_error` does,
+    /// rejected sessions are reported as expired
+    fn error(status: StatusCode, body: &str, credentials: &Credentials) -> Option<Error> {
+        match Client::status_error(status, body) {
+            Some(Error::Unauthorized) if Client::has_session(credentials) => {
+                Some(Error::SessionExpired)
+            }
+            e => e,
+        }
+    }
+
+    /// Returns true for session credentials holding a cookie, the empty
+    /// session `login` sends its request with has nothing that could expire
+    /// so a 401 to it means the username or password was wrong
+    fn has_session(credentials: &Credentials) -> bool {
+        match credentials {
+            Credentials::Session(ref name, _) => !name.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Api {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.group(), self.version())
+    }
+}
+
+// ============================================================================
+// Crate Functions
+// ============================================================================
+/// Characters left alone when encoding a path segment or query component,
+/// anything outside of the unreserved set in RFC 3986 is percent-encoded.
+const UNRESERVED: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent-encodes a value so that it can be safely interpolated into a
+/// single segment of an endpoint path, e.g. a key containing a slash or space.
+pub(crate) fn encode_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, UNRESERVED).to_string()
+}
+
+/// Percent-encodes a query string key or value, e.g. an email address
+/// containing `@` or `+`. Query components are encoded exactly like path
+/// segments.
+pub(crate) fn encode_component(component: &str) -> String {
+    encode_segment(component)
 }
 
 // ============================================================================
@@ -164,10 +948,10 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_hashmap_to_query() {
-        let mut query: HashMap<String, String> = HashMap::new();
-        query.insert(String::from("q"), String::from("true"));
-        query.insert(String::from("h"), String::from("other-text"));
+    fn test_query_to_string() {
+        let mut query = Query::new();
+        query.insert("q", "true");
+        query.insert("h", "other-text");
 
         let unpacked = Client::unpack_query(&query);
 
@@ -176,8 +960,49 @@ mod tests {
     }
 
     #[test]
-    fn test_empty_hashmap_empty_query() {
-        let query: HashMap<String, String> = HashMap::new();
+    fn test_query_repeated_keys() {
+        let mut query = Query::new();
+        query.append("fields", "summary");
+        query.append("fields", "status");
+
+        assert_eq!(
+            Client::unpack_query(&query),
+            "?fields=summary&fields=status"
+        );
+    }
+
+    #[test]
+    fn test_query_values_encoded() {
+        let mut query = Query::new();
+        query.insert("username", "fred+jira@example.com");
+        query.insert("jql", "a = b&c");
+
+        assert_eq!(
+            Client::unpack_query(&query),
+            "?username=fred%2Bjira%40example.com&jql=a%20%3D%20b%26c"
+        );
+    }
+
+    #[test]
+    fn test_encode_segment() {
+        assert_eq!(encode_segment("TEST-1"), "TEST-1");
+        assert_eq!(encode_segment("a/b c?d"), "a%2Fb%20c%3Fd");
+        assert_eq!(encode_segment("v1.0_rc~2"), "v1.0_rc~2");
+        assert_eq!(encode_segment("ü"), "%C3%BC");
+    }
+
+    #[test]
+    fn test_api_endpoint() {
+        assert_eq!(Api::V2.endpoint("applicationrole"), "api/2/applicationrole");
+        assert_eq!(Api::V3.endpoint("issue"), "api/3/issue");
+        assert_eq!(Api::Agile.endpoint("board/1/sprint"), "agile/1.0/board/1/sprint");
+        assert_eq!(Api::Auth.endpoint("session"), "auth/1/session");
+        assert_eq!(format!("{}/issue/{}", Api::V2, "TEST-1"), "api/2/issue/TEST-1");
+    }
+
+    #[test]
+    fn test_empty_query() {
+        let query = Query::new();
         assert_eq!(Client::unpack_query(&query), "");
     }
 }
@@ -1,4 +1,5 @@
-//! Handles JIRA credentials, currently only basic authentication is supported
+//! Handles JIRA credentials, basic authentication, OAuth access tokens,
+//! session cookies and Atlassian Connect app JWTs are supported
 
 // ============================================================================
 // Use
@@ -16,7 +17,18 @@ pub enum Credentials {
     Basic(String, String),
     /// Supply the access token here, your app should be responsible for
     /// defining scope and doing the OAuth dance.
-    OAuth(String)
+    OAuth(String),
+    /// A session cookie name and value as returned by `rest/auth/1/session`,
+    /// usually obtained through `Client::login`
+    Session(String, String),
+    /// An Atlassian Connect app installation, every request is signed with a
+    /// JWT made from the shared secret. Set the account id to act as a user
+    /// rather than the app.
+    ConnectJwt {
+        key: String,
+        shared_secret: String,
+        account_id: Option<String>,
+    },
 }
 
 impl Credentials {
@@ -44,6 +56,37 @@ impl Credentials {
     {
         Ok(Credentials::OAuth(access_token.into()))
     }
+
+    /// Builds new credentials from an existing session cookie e.g.
+    /// ("JSESSIONID", "6E3487971234567896704A9EB4AE501F"), the cookie is sent
+    /// with every request.
+    pub fn new_session<N, V>(name: N, value: V) -> Result<Credentials>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        Ok(Credentials::Session(name.into(), value.into()))
+    }
+
+    /// Builds new credentials for an Atlassian Connect app given the app key
+    /// and the shared secret received in the installed lifecycle callback.
+    /// For more info see:
+    /// https://developer.atlassian.com/cloud/jira/platform/understanding-jwt-for-connect-apps/
+    pub fn new_connect_jwt<K, S>(
+        key: K,
+        shared_secret: S,
+        account_id: Option<String>,
+    ) -> Result<Credentials>
+    where
+        K: Into<String>,
+        S: Into<String>,
+    {
+        Ok(Credentials::ConnectJwt {
+            key: key.into(),
+            shared_secret: shared_secret.into(),
+            account_id,
+        })
+    }
 }
 
 // ============================================================================
@@ -63,6 +106,15 @@ mod tests {
             }
     }
 
+    #[test]
+    fn test_instantiate_session_credentials() {
+        if let Credentials::Session(n, v) =
+            Credentials::new_session("JSESSIONID", "6E34879712").unwrap() {
+                assert_eq!(n, "JSESSIONID");
+                assert_eq!(v, "6E34879712");
+            }
+    }
+
     #[test]
     fn test_instantiate_oauth_credentials() {
         if let Credentials::OAuth(a) = 
@@ -70,4 +122,14 @@ mod tests {
                 assert_eq!(a, "some_token");
             }
     }
+
+    #[test]
+    fn test_instantiate_connect_jwt_credentials() {
+        if let Credentials::ConnectJwt { key, shared_secret, account_id } =
+            Credentials::new_connect_jwt("my-app", "secret", None).unwrap() {
+                assert_eq!(key, "my-app");
+                assert_eq!(shared_secret, "secret");
+                assert_eq!(account_id, None);
+            }
+    }
 }
@@ -5,6 +5,7 @@
 // ============================================================================
 use reqwest::Error as HttpError;
 use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde_json::error::Error as SerdeError;
 use std::collections::BTreeMap;
@@ -14,13 +15,26 @@ use std::io::Error as IoError;
 // Public Structures
 // ============================================================================
 /// represents a general jira error response
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Default)]
 pub struct Errors {
-    #[serde(rename = "errorMessages")]
+    #[serde(rename = "errorMessages", default)]
     pub error_messages: Vec<String>,
+    #[serde(default)]
     pub errors: BTreeMap<String, String>,
 }
 
+impl Errors {
+    /// Reads an error response, bodies that aren't in the usual format such
+    /// as HTML from a proxy are kept as the only error message so the cause
+    /// isn't lost to a deserialization failure.
+    pub(crate) fn from_body(body: &str) -> Errors {
+        serde_json::from_str::<Errors>(body).unwrap_or_else(|_| Errors {
+            error_messages: vec![body.trim().to_string()],
+            errors: BTreeMap::new(),
+        })
+    }
+}
+
 // ============================================================================
 // Public Enums
 // ============================================================================
@@ -28,13 +42,27 @@ pub struct Errors {
 /// to jira
 #[derive(Debug)]
 pub enum Error {
-    /// error associated with http request
-    Http(HttpError),
+    /// the connection to jira could not be established, safe to retry
+    Connect(HttpError),
+    /// the request timed out, safe to retry
+    Timeout(HttpError),
+    /// a response could not be deserialized, retrying won't help. `path` is
+    /// the JSON path of the value that failed e.g. `fields.timespent` and
+    /// `snippet` the raw body around the failure
+    Decode {
+        endpoint: String,
+        path: String,
+        source: SerdeError,
+        snippet: String,
+    },
+    /// any other failure sending the request or reading the response
+    Transport(HttpError),
     /// error associated IO
     IO(IoError),
-    /// error associated with parsing or serializing
+    /// error associated with serializing a request body, failures reading a
+    /// response are reported as `Decode`
     Serde(SerdeError),
-    /// client request errors
+    /// error responses from jira along with the messages it gave
     Fault { code: StatusCode, errors: Errors },
     /// invalid credentials
     Unauthorized,
@@ -46,11 +74,110 @@ pub enum Error {
     MethodNotAllowed,
     /// Page not found
     NotFound,
+    /// The response body was larger than the configured maximum
+    ResponseTooLarge,
+    /// The session cookie is no longer valid, log in again
+    SessionExpired,
+    /// The requested page was rejected before being sent, e.g. a page size
+    /// of zero
+    InvalidPagination(String),
+    /// The issue type can't be used in the project, `available` lists the
+    /// names of the issue types that can
+    InvalidIssueType { name: String, available: Vec<String> },
+    /// No transition with the name is available to the issue in its current
+    /// status, `available` lists the names of the transitions that are
+    TransitionNotFound { name: String, available: Vec<String> },
+    /// The project has no component with the name, `available` lists the
+    /// names of its components
+    ComponentNotFound { name: String, available: Vec<String> },
+    /// The project has no version with the name, `available` lists the
+    /// names of its versions
+    VersionNotFound { name: String, available: Vec<String> },
+    /// The instance has no field with the name, `available` lists the names
+    /// of its fields
+    FieldNotFound { name: String, available: Vec<String> },
+    /// The issue can't be created as a subtask of the parent, e.g. the issue
+    /// type isn't a subtask type or the parent is in another project
+    InvalidSubtask(String),
+    /// A field required to build a request wasn't set
+    MissingField(String),
+    /// The URL isn't on the host of the client so the request wasn't sent,
+    /// this keeps credentials from leaking to other hosts
+    ForeignUrl(String),
+    /// Replaying fixtures and the request wasn't recorded, or its fixture
+    /// can't be read
+    FixtureNotFound(String),
+    /// The instance doesn't offer the feature, e.g. a Cloud only endpoint on
+    /// JIRA Server, the request wasn't sent
+    NotSupported(String),
+    /// A bulk operation stopped at an earlier failure before it got to this
+    /// item, nothing was sent for it
+    NotAttempted,
+}
+
+impl Error {
+    /// Returns true if sending the same request again may succeed, that is
+    /// for connect failures, timeouts, other transport failures, 429 and 5xx
+    /// responses. This is what the retry policy of the client retries on.
+    /// Requests rejected for what they contain, requests that can't be
+    /// serialized and responses that can't be decoded fail the same way
+    /// every time.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Connect(_) | Error::Timeout(_) => true,
+            Error::Transport(e) => !e.is_serialization(),
+            Error::Fault { code, .. } => {
+                *code == StatusCode::TOO_MANY_REQUESTS || code.is_server_error()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the HTTP status of the response behind the error, None if the
+    /// error happened before a response was received or isn't about one
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::Fault { code, .. } => Some(code.as_u16()),
+            Error::Unauthorized | Error::SessionExpired => Some(401),
+            Error::Forbidden => Some(403),
+            Error::NotFound => Some(404),
+            Error::MethodNotAllowed => Some(405),
+            Error::PreconditionFailed => Some(412),
+            Error::Connect(e) | Error::Timeout(e) | Error::Transport(e) => {
+                e.status().map(|s| s.as_u16())
+            }
+            _ => None,
+        }
+    }
 }
 
 // ============================================================================
 // Error Implementations
 // ============================================================================
+impl Error {
+    /// Deserializes a response body, a failure is reported as `Decode` with
+    /// the path of the value that failed and the body around it
+    pub(crate) fn decode<D>(endpoint: &str, body: &str) -> Result<D, Error>
+    where
+        D: DeserializeOwned,
+    {
+        let de = &mut serde_json::Deserializer::from_str(body);
+
+        serde_path_to_error::deserialize(de).map_err(|e| {
+            let path = e.path().to_string();
+            let source = e.into_inner();
+            let snippet = snippet(body, source.line(), source.column());
+
+            Error::Decode {
+                endpoint: endpoint.to_string(),
+                path,
+                source,
+                snippet,
+            }
+        })
+    }
+}
+
 impl From<SerdeError> for Error {
     fn from(error: SerdeError) -> Error {
         Error::Serde(error)
@@ -59,7 +186,19 @@ impl From<SerdeError> for Error {
 
 impl From<HttpError> for Error {
     fn from(error: HttpError) -> Error {
-        Error::Http(error)
+        let connect = error
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<hyper::Error>())
+            .map(|e| e.is_connect())
+            .unwrap_or(false);
+
+        if error.is_timeout() {
+            Error::Timeout(error)
+        } else if connect {
+            Error::Connect(error)
+        } else {
+            Error::Transport(error)
+        }
     }
 }
 
@@ -74,14 +213,80 @@ impl ::std::fmt::Display for Error {
         use crate::Error::*;
 
         match *self {
-            Http(ref e) => writeln!(f, "Http Error: {}", e),
+            Connect(ref e) => writeln!(f, "Connection Error: {}", e),
+            Timeout(ref e) => writeln!(f, "Timeout Error: {}", e),
+            Decode {
+                ref endpoint,
+                ref path,
+                ref source,
+                ref snippet,
+            } => writeln!(
+                f,
+                "Decode Error: {} at {} in the response from {} near: {}",
+                source, path, endpoint, snippet
+            ),
+            Transport(ref e) => writeln!(f, "Http Error: {}", e),
             IO(ref e) => writeln!(f, "IO Error: {}", e),
             Serde(ref e) => writeln!(f, "Serialization Error: {}", e),
             Fault {
                 ref code,
                 ref errors,
             } => writeln!(f, "Jira Client Error ({}):\n{:#?}", code, errors),
-            _ => writeln!(f, "Could not connect to Jira: {}!", self),
+            ResponseTooLarge => writeln!(f, "Response body exceeded the maximum size"),
+            SessionExpired => writeln!(f, "Jira session has expired, log in again"),
+            InvalidPagination(ref e) => writeln!(f, "Invalid pagination: {}", e),
+            InvalidIssueType {
+                ref name,
+                ref available,
+            } => writeln!(
+                f,
+                "Issue type {} is not available in the project, expected one of: {}",
+                name,
+                available.join(", ")
+            ),
+            TransitionNotFound {
+                ref name,
+                ref available,
+            } => writeln!(
+                f,
+                "Transition {} is not available in the issue's current status, expected one of: {}",
+                name,
+                available.join(", ")
+            ),
+            MissingField(ref field) => writeln!(f, "Required field {} is not set", field),
+            ForeignUrl(ref url) => writeln!(f, "Refusing to send credentials to {}", url),
+            NotSupported(ref reason) => writeln!(f, "Not supported: {}", reason),
+            ComponentNotFound {
+                ref name,
+                ref available,
+            } => writeln!(
+                f,
+                "Component {} is not in the project, expected one of: {}",
+                name,
+                available.join(", ")
+            ),
+            VersionNotFound {
+                ref name,
+                ref available,
+            } => writeln!(
+                f,
+                "Version {} is not in the project, expected one of: {}",
+                name,
+                available.join(", ")
+            ),
+            FieldNotFound {
+                ref name,
+                ref available,
+            } => writeln!(
+                f,
+                "Field {} is not on the instance, expected one of: {}",
+                name,
+                available.join(", ")
+            ),
+            InvalidSubtask(ref reason) => writeln!(f, "Invalid subtask: {}", reason),
+            FixtureNotFound(ref request) => writeln!(f, "No fixture recorded for {}", request),
+            NotAttempted => writeln!(f, "Not attempted, an earlier item failed"),
+            _ => writeln!(f, "Could not connect to Jira: {:?}!", self),
         }
     }
 }
@@ -91,7 +296,8 @@ impl ::std::error::Error for Error {
         use crate::Error::*;
 
         match *self {
-            Http(ref e) => Some(e),
+            Connect(ref e) | Timeout(ref e) | Transport(ref e) => Some(e),
+            Decode { ref source, .. } => Some(source),
             IO(ref e) => Some(e),
             Serde(ref e) => Some(e),
             Fault { .. } => None,
@@ -99,3 +305,75 @@ impl ::std::error::Error for Error {
         }
     }
 }
+
+// ============================================================================
+// Private
+// ============================================================================
+/// Characters of the body kept either side of a decode failure
+const SNIPPET_CONTEXT: usize = 40;
+
+/// Returns the body around the 1-based line and column serde reported, the
+/// start of the body if it gave none
+fn snippet(body: &str, line: usize, column: usize) -> String {
+    let offset = body
+        .split('\n')
+        .take(line.saturating_sub(1))
+        .map(|l| l.len() + 1)
+        .sum::<usize>()
+        + column.saturating_sub(1);
+    let chars: Vec<(usize, char)> = body.char_indices().collect();
+    let at = chars.iter().position(|(i, _)| *i >= offset).unwrap_or(chars.len());
+    let start = at.saturating_sub(SNIPPET_CONTEXT);
+    let end = (at + SNIPPET_CONTEXT).min(chars.len());
+
+    chars[start..end].iter().map(|(_, c)| c).collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Fields {
+        #[allow(dead_code)]
+        timespent: Option<i64>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Issue {
+        #[allow(dead_code)]
+        fields: Fields,
+    }
+
+    #[test]
+    fn test_decode_reports_path() {
+        let body = r#"{"key": "EX-1", "fields": {"timespent": "1h"}}"#;
+
+        match Error::decode::<Issue>("api/2/issue/EX-1", body) {
+            Err(Error::Decode {
+                endpoint,
+                path,
+                snippet,
+                ..
+            }) => {
+                assert_eq!(endpoint, "api/2/issue/EX-1");
+                assert_eq!(path, "fields.timespent");
+                assert!(snippet.contains(r#""timespent": "1h""#));
+            }
+            r => panic!("expected Decode, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_snippet_truncates() {
+        let body = format!("{}X{}", "a".repeat(100), "b".repeat(100));
+        let s = snippet(&body, 1, 101);
+
+        assert_eq!(s.len(), SNIPPET_CONTEXT * 2);
+        assert!(s.starts_with('a') && s.ends_with('b'));
+        assert_eq!(s.find('X'), Some(SNIPPET_CONTEXT));
+    }
+}
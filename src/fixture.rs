@@ -0,0 +1,365 @@
+//! Records responses to disk and serves them back later, so tests can run
+//! against the shapes a real instance returns without reaching it. Both sit
+//! in the client as a transport, see `ClientBuilder::record_fixtures`,
+//! `ClientBuilder::replay_fixtures` and `ClientBuilder::fixtures_from_env`.
+//! Fixtures are keyed by method, path, query and body, the host isn't part of
+//! the key so recordings can be replayed against any host.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::transport::{Network, Reply, Transport};
+use crate::Error;
+use crate::Result;
+use crate::{Deserialize, Serialize};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::header::{AUTHORIZATION, COOKIE, IF_NONE_MATCH, PROXY_AUTHORIZATION, SET_COOKIE};
+use reqwest::{Method, Request, StatusCode, Url};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+/// Sends requests over the network and writes every exchange to a directory
+#[derive(Debug)]
+pub(crate) struct Recorder(pub PathBuf);
+
+/// Answers requests from the recordings in a directory instead of the network
+#[derive(Debug)]
+pub(crate) struct Replayer(pub PathBuf);
+
+/// Returns the transport set through `JIRAC_RECORD` or `JIRAC_REPLAY`, if
+/// both are set recording wins
+pub(crate) fn from_env() -> Option<Arc<dyn Transport>> {
+    match (env::var_os("JIRAC_RECORD"), env::var_os("JIRAC_REPLAY")) {
+        (Some(dir), _) if !dir.is_empty() => Some(Arc::new(Recorder(dir.into()))),
+        (_, Some(dir)) if !dir.is_empty() => Some(Arc::new(Replayer(dir.into()))),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl Transport for Recorder {
+    /// Sends the request and writes the exchange, anything that could
+    /// authenticate a request is scrubbed first. A conditional request is
+    /// sent unconditionally so the recording holds the body rather than a
+    /// 304 that only the cache of this client could answer.
+    fn execute(&self, net: &Network, mut req: Request, body: Option<&[u8]>) -> Result<Reply> {
+        req.headers_mut().remove(IF_NONE_MATCH);
+
+        let method = req.method().clone();
+        let key = key(req.url());
+        let request_body = body.map(|b| scrub_request_body(&String::from_utf8_lossy(b)));
+        let name = file_name(&method, &key, request_body.as_deref());
+        let request = RecordedRequest {
+            method: method.to_string(),
+            url: scrub_url(&key),
+            headers: scrub_headers(req.headers()),
+            body: request_body,
+        };
+        let reply = net.send(req)?;
+        let (body, body_base64) = match String::from_utf8(reply.body.clone()) {
+            Ok(text) => (scrub_response_body(&text), None),
+            Err(_) => (String::new(), Some(base64::encode(&reply.body))),
+        };
+        let fixture = Fixture {
+            request,
+            response: RecordedResponse {
+                status: reply.status.as_u16(),
+                headers: scrub_headers(&reply.headers),
+                body,
+                body_base64,
+            },
+        };
+
+        write(&self.0, &name, &serde_json::to_vec_pretty(&fixture)?)?;
+
+        Ok(reply)
+    }
+}
+
+impl Transport for Replayer {
+    /// Returns the recorded response to a request, a request that wasn't
+    /// recorded fails with `Error::FixtureNotFound` rather than reaching the
+    /// network
+    fn execute(&self, _net: &Network, req: Request, body: Option<&[u8]>) -> Result<Reply> {
+        let key = key(req.url());
+        let request_body = body.map(|b| scrub_request_body(&String::from_utf8_lossy(b)));
+        let path = self.0.join(file_name(req.method(), &key, request_body.as_deref()));
+
+        if !path.exists() {
+            return Err(Error::FixtureNotFound(format!("{} {}", req.method(), scrub_url(&key))));
+        }
+
+        let fixture: Fixture = serde_json::from_slice(&fs::read(&path)?)?;
+        let mut headers = HeaderMap::new();
+
+        for (name, value) in fixture.response.headers {
+            let name = name.parse::<HeaderName>();
+            let value = value.parse::<HeaderValue>();
+
+            if let (Ok(name), Ok(value)) = (name, value) {
+                headers.append(name, value);
+            }
+        }
+
+        let body = match fixture.response.body_base64 {
+            Some(encoded) => base64::decode(&encoded)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?,
+            None => fixture.response.body.into_bytes(),
+        };
+
+        Ok(Reply {
+            status: StatusCode::from_u16(fixture.response.status)
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            headers,
+            body,
+        })
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+/// Replaces the values of headers, query parameters and JSON fields that hold
+/// credentials
+const REDACTED: &str = "<redacted>";
+
+/// Names of headers and query parameters holding secrets, e.g. a token passed
+/// in the URL. A name is sensitive if it contains any of these, ignoring case.
+const SENSITIVE_NAMES: &[&str] = &["password", "secret", "token", "jwt"];
+
+/// JSON fields of request bodies holding credentials, e.g. the password sent
+/// to log in. These match exactly so that fields like `nextPageToken` survive.
+const CREDENTIAL_FIELDS: &[&str] =
+    &["password", "sharedSecret", "clientSecret", "accessToken", "refreshToken"];
+
+#[derive(Serialize, Deserialize)]
+struct Fixture {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    url: String,
+    #[serde(default)]
+    headers: Vec<(String, String)>,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: Vec<(String, String)>,
+    #[serde(default)]
+    body: String,
+    #[serde(rename = "bodyBase64", skip_serializing_if = "Option::is_none", default)]
+    body_base64: Option<String>,
+}
+
+/// Returns the file a request is recorded in, a readable prefix from the
+/// method and endpoint followed by a hash of the whole key. The scrubbed body
+/// is part of the key so requests to the same URL that only differ in their
+/// body, such as the pages of a search, get a file each.
+fn file_name(method: &Method, url: &str, body: Option<&str>) -> String {
+    let key = match body {
+        Some(body) => format!("{} {}\n{}", method, url, body),
+        None => format!("{} {}", method, url),
+    };
+    let hash: String = Sha256::digest(key.as_bytes())
+        .iter()
+        .take(6)
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let path = url.split('?').next().unwrap_or_default();
+    let readable: String = path
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .take(80)
+        .collect();
+
+    format!("{}_{}-{}.json", method.as_str().to_lowercase(), readable, hash)
+}
+
+/// Returns the path and query of a URL, what fixtures are keyed by
+fn key(url: &Url) -> String {
+    let path = url.path().trim_start_matches('/');
+
+    match url.query() {
+        Some(query) => format!("{}?{}", path, query),
+        None => path.to_string(),
+    }
+}
+
+fn sensitive(name: &str) -> bool {
+    let name = name.to_lowercase();
+
+    SENSITIVE_NAMES.iter().any(|s| name.contains(s))
+}
+
+/// Redacts the values of query parameters that hold secrets, e.g. a token
+/// passed in the URL
+fn scrub_url(url: &str) -> String {
+    let mut parts = url.splitn(2, '?');
+    let path = parts.next().unwrap_or_default();
+    let query = match parts.next() {
+        Some(query) => query,
+        None => return path.to_string(),
+    };
+    let query: Vec<String> = query
+        .split('&')
+        .map(|pair| match pair.split('=').next() {
+            Some(name) if sensitive(name) => format!("{}={}", name, REDACTED),
+            _ => pair.to_string(),
+        })
+        .collect();
+
+    format!("{}?{}", path, query.join("&"))
+}
+
+fn scrub_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(k, v)| {
+            let sensitive = k == AUTHORIZATION
+                || k == PROXY_AUTHORIZATION
+                || k == COOKIE
+                || k == SET_COOKIE
+                || sensitive(k.as_str());
+            let value = if sensitive {
+                REDACTED.to_string()
+            } else {
+                v.to_str().unwrap_or(REDACTED).to_string()
+            };
+
+            (k.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+/// Redacts the credentials in a JSON request body. Bodies that aren't JSON
+/// are kept as is.
+fn scrub_request_body(body: &str) -> String {
+    let mut value = match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(value) => value,
+        Err(_) => return body.to_string(),
+    };
+
+    scrub_value(&mut value);
+    value.to_string()
+}
+
+/// Redacts the session cookie returned when logging in, the rest of a
+/// response is kept as is so that replays see what JIRA sent, cursors and
+/// entity properties included
+fn scrub_response_body(body: &str) -> String {
+    let mut value = match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(value) => value,
+        Err(_) => return body.to_string(),
+    };
+
+    match value.get_mut("session").and_then(|s| s.as_object_mut()) {
+        Some(session) if session.contains_key("value") => {
+            session.insert("value".to_string(), REDACTED.into());
+            value.to_string()
+        }
+        _ => body.to_string(),
+    }
+}
+
+fn scrub_value(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                if CREDENTIAL_FIELDS.contains(&k.as_str()) {
+                    *v = REDACTED.into();
+                } else {
+                    scrub_value(v);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(scrub_value),
+        _ => {}
+    }
+}
+
+fn write(dir: &Path, name: &str, contents: &[u8]) -> Result<()> {
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(name), contents)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        let name = file_name(&Method::GET, "rest/api/2/issue/EX-1?fields=summary", None);
+
+        assert!(name.starts_with("get_rest_api_2_issue_EX_1-"));
+        assert_ne!(name, file_name(&Method::GET, "rest/api/2/issue/EX-1?fields=status", None));
+        assert_ne!(name, file_name(&Method::PUT, "rest/api/2/issue/EX-1?fields=summary", None));
+
+        let page = |start| format!(r#"{{"jql": "project = EX", "startAt": {}}}"#, start);
+        let first = file_name(&Method::POST, "rest/api/2/search", Some(&page(0)));
+
+        assert_ne!(first, file_name(&Method::POST, "rest/api/2/search", Some(&page(50))));
+        assert_eq!(first, file_name(&Method::POST, "rest/api/2/search", Some(&page(0))));
+    }
+
+    #[test]
+    fn test_key() {
+        let url = Url::parse("https://jira.example.com/jira/rest/api/2/search?jql=x").unwrap();
+
+        assert_eq!(key(&url), "jira/rest/api/2/search?jql=x");
+    }
+
+    #[test]
+    fn test_scrub() {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Basic dGVzdDp0ZXN0"));
+        headers.insert(SET_COOKIE, HeaderValue::from_static("JSESSIONID=6E3487971234"));
+        headers.insert("x-ausername", HeaderValue::from_static("fred"));
+
+        let scrubbed = scrub_headers(&headers);
+
+        assert!(scrubbed.contains(&("authorization".to_string(), REDACTED.to_string())));
+        assert!(scrubbed.contains(&("set-cookie".to_string(), REDACTED.to_string())));
+        assert!(scrubbed.contains(&("x-ausername".to_string(), "fred".to_string())));
+
+        let login = r#"{"username": "fred", "password": "secret"}"#;
+        let session = r#"{"session": {"name": "JSESSIONID", "value": "6E3487971234"}}"#;
+
+        let page = r#"{"nextPageToken": "CAEaAggB", "values": []}"#;
+
+        assert!(!scrub_request_body(login).contains("secret"));
+        assert!(scrub_request_body(login).contains("fred"));
+        assert!(scrub_request_body(page).contains("CAEaAggB"));
+        assert!(!scrub_response_body(session).contains("6E3487971234"));
+        assert_eq!(scrub_response_body(page), page);
+        assert_eq!(scrub_response_body("<html></html>"), "<html></html>");
+
+        assert_eq!(
+            scrub_url("rest/api/2/myself?jwt=abc.def&expand=groups&access_token=xyz"),
+            "rest/api/2/myself?jwt=<redacted>&expand=groups&access_token=<redacted>"
+        );
+        assert_eq!(scrub_url("rest/api/2/myself"), "rest/api/2/myself");
+    }
+}
@@ -0,0 +1,201 @@
+//! Signs requests for Atlassian Connect apps. Every request carries a JWT
+//! signed with the shared secret of the app installation, the `qsh` claim
+//! binds the token to the method, path and query of that one request. For
+//! more info see:
+//! https://developer.atlassian.com/cloud/jira/platform/understanding-jwt-for-connect-apps/
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_component;
+use hmac::{Hmac, Mac};
+use percent_encoding::percent_decode_str;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+// ============================================================================
+// Crate Functions
+// ============================================================================
+/// Returns a signed token for a request, `path` is relative to the base URL
+/// of the instance and `query` is the encoded query string without the `?`.
+/// `now` is the current time in seconds since the epoch.
+pub(crate) fn token(
+    key: &str,
+    shared_secret: &str,
+    account_id: Option<&str>,
+    method: &str,
+    path: &str,
+    query: &str,
+    now: u64,
+) -> String {
+    let header = base64::encode_config(r#"{"alg":"HS256","typ":"JWT"}"#, base64::URL_SAFE_NO_PAD);
+    let claims = Claims {
+        iss: key,
+        iat: now,
+        exp: now + LIFETIME,
+        qsh: query_hash(method, path, query),
+        sub: account_id,
+    };
+    // Serializing a struct of strings and numbers can't fail
+    let claims = serde_json::to_vec(&claims).unwrap_or_default();
+    let signing_input = format!(
+        "{}.{}",
+        header,
+        base64::encode_config(&claims, base64::URL_SAFE_NO_PAD)
+    );
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(shared_secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(signing_input.as_bytes());
+
+    let signature = mac.finalize().into_bytes();
+
+    format!(
+        "{}.{}",
+        signing_input,
+        base64::encode_config(&signature, base64::URL_SAFE_NO_PAD)
+    )
+}
+
+/// Returns the `qsh` claim, the hex encoded SHA-256 of the canonical request
+pub(crate) fn query_hash(method: &str, path: &str, query: &str) -> String {
+    Sha256::digest(canonical_request(method, path, query).as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Returns the canonical form of a request, `METHOD&path&query`
+pub(crate) fn canonical_request(method: &str, path: &str, query: &str) -> String {
+    format!(
+        "{}&{}&{}",
+        method.to_uppercase(),
+        canonical_path(path),
+        canonical_query(query)
+    )
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+/// Seconds a token is valid for, Atlassian recommends a few minutes
+const LIFETIME: u64 = 180;
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    iat: u64,
+    exp: u64,
+    qsh: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<&'a str>,
+}
+
+/// The path always starts with a `/` and never ends with one unless it is
+/// the root, an `&` would be read as a separator so it is encoded
+fn canonical_path(path: &str) -> String {
+    let path = path.trim_end_matches('/');
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    };
+
+    path.replace('&', "%26")
+}
+
+/// Parameters are sorted by key and encoded per RFC 3986, the values of a
+/// repeated key are sorted and joined with commas. The `jwt` parameter is
+/// left out as it holds the token itself.
+fn canonical_query(query: &str) -> String {
+    let mut params: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let mut parts = pair.splitn(2, '=');
+        let key = decode(parts.next().unwrap_or_default());
+        let value = decode(parts.next().unwrap_or_default());
+
+        if key != "jwt" {
+            params.entry(key).or_default().push(value);
+        }
+    }
+
+    params
+        .iter_mut()
+        .map(|(key, values)| {
+            values.sort();
+
+            let values: Vec<String> = values.iter().map(|v| encode_component(v)).collect();
+
+            format!("{}={}", encode_component(key), values.join(","))
+        })
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+fn decode(component: &str) -> String {
+    percent_decode_str(&component.replace('+', " "))
+        .decode_utf8_lossy()
+        .into_owned()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_request() {
+        // The example from Atlassian's documentation of the query string hash
+        assert_eq!(
+            canonical_request(
+                "get",
+                "/path/to/service",
+                "zee_last=param&repeated=parameter%201&first=param&repeated=parameter%202"
+            ),
+            "GET&/path/to/service&first=param&repeated=parameter%201,parameter%202&zee_last=param"
+        );
+    }
+
+    #[test]
+    fn test_canonical_path_and_query() {
+        assert_eq!(canonical_path(""), "/");
+        assert_eq!(canonical_path("/rest/api/2/issue/"), "/rest/api/2/issue");
+        assert_eq!(canonical_path("/a&b"), "/a%26b");
+        assert_eq!(canonical_query("jwt=abc&b=x+y&a=*~"), "a=%2A~&b=x%20y");
+        assert_eq!(canonical_query(""), "");
+    }
+
+    #[test]
+    fn test_query_hash() {
+        // Atlassian's example of the canonical request for a GET with no
+        // query string and its published hash
+        assert_eq!(
+            query_hash("GET", "/", ""),
+            "c88caad15a1c1a900b8ac08aa9686f4e8184539bea1deda36e2f649430df3239"
+        );
+    }
+
+    #[test]
+    fn test_token() {
+        let t = token("my-app", "secret", None, "GET", "/rest/api/2/myself", "", 1_000);
+        let parts: Vec<&str> = t.split('.').collect();
+        let claims = base64::decode_config(parts[1], base64::URL_SAFE_NO_PAD).unwrap();
+        let claims: serde_json::Value = serde_json::from_slice(&claims).unwrap();
+
+        assert_eq!(parts.len(), 3);
+        assert_eq!(claims["iss"], "my-app");
+        assert_eq!(claims["exp"], 1_180);
+        assert_eq!(claims["qsh"], query_hash("GET", "/rest/api/2/myself", ""));
+        assert!(claims.get("sub").is_none());
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+        mac.update(format!("{}.{}", parts[0], parts[1]).as_bytes());
+
+        let signature = base64::decode_config(parts[2], base64::URL_SAFE_NO_PAD).unwrap();
+        assert!(mac.verify_slice(&signature).is_ok());
+    }
+}
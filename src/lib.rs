@@ -1,5 +1,4 @@
-//!
-//!
+//! A client for interfacing with the JIRA REST API
 
 // ============================================================================
 // External Crates
@@ -18,8 +17,15 @@ pub mod v2;
 // ============================================================================
 // Private Modules
 // ============================================================================
+mod cache;
 mod errors;
+mod fixture;
+mod jwt;
 mod options;
+mod rate_limit;
+mod retry;
+mod trace;
+mod transport;
 
 // ============================================================================
 // Use
@@ -28,6 +34,8 @@ pub use crate::client::*;
 pub use crate::credentials::*;
 pub use crate::errors::*;
 pub use crate::options::*;
+pub use crate::rate_limit::*;
+pub use crate::retry::*;
 pub use crate::serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -2,17 +2,183 @@
 //! the API
 
 // ============================================================================
-// Use
+// Public Structures
 // ============================================================================
-use std::collections::HashMap;
+/// An ordered list of query string parameters. Unlike a map a key may be given
+/// more than once which some endpoints use for multiple values, e.g.
+/// `fields=summary&fields=status`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Query(Vec<(String, String)>);
+
+impl Query {
+    /// Returns an empty query
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    /// Sets a parameter, replacing any values already given for the key
+    pub fn insert<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let key = key.into();
+
+        self.0.retain(|(k, _)| *k != key);
+        self.0.push((key, value.into()));
+    }
+
+    /// Adds a parameter, keeping any values already given for the key
+    pub fn append<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.0.push((key.into(), value.into()));
+    }
+
+    /// Returns the first value given for the key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns every value given for the key in the order they were added
+    pub fn get_all(&self, key: &str) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+
+    /// Merges another query into this one, keys present in `other` replace
+    /// the values held here.
+    pub fn extend(&mut self, other: Query) {
+        self.0.retain(|(k, _)| !other.0.iter().any(|(o, _)| o == k));
+        self.0.extend(other.0);
+    }
+
+    /// Returns true if there are no parameters
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the parameters in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = &(String, String)> {
+        self.0.iter()
+    }
+}
+
+/// Conditions a write is sent with for optimistic concurrency, JIRA rejects
+/// the write with `Error::PreconditionFailed` when they don't hold. Pass it to
+/// a write method or apply it to any request with `Client::precondition`.
+///
+/// Examples:
+/// Precondition::if_match(role_hash)
+/// Precondition::if_none_match("*")
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Precondition {
+    /// Only write if the current version of the entity matches this hash
+    pub if_match: Option<String>,
+
+    /// Only write if the current version of the entity doesn't match this
+    /// hash, "*" only writes if the entity doesn't exist
+    pub if_none_match: Option<String>,
+}
+
+impl Precondition {
+    /// Returns a precondition requiring the entity to match the given hash
+    pub fn if_match<H>(hash: H) -> Self
+    where
+        H: Into<String>,
+    {
+        Precondition {
+            if_match: Some(hash.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns a precondition requiring the entity not to match the given
+    /// hash
+    pub fn if_none_match<H>(hash: H) -> Self
+    where
+        H: Into<String>,
+    {
+        Precondition {
+            if_none_match: Some(hash.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if no condition is set
+    pub fn is_empty(&self) -> bool {
+        self.if_match.is_none() && self.if_none_match.is_none()
+    }
+}
 
 // ============================================================================
 // Traits
 // ============================================================================
 pub trait Options {
-    fn to_query(&self) -> HashMap<String, String>;
+    fn to_query(&self) -> Query;
 }
 
 pub trait QueryOptions {
     fn to_string(&self) -> String;
-}
\ No newline at end of file
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_replaces() {
+        let mut q = Query::new();
+        q.insert("a", "1");
+        q.insert("a", "2");
+
+        assert_eq!(q.get_all("a"), vec!["2"]);
+    }
+
+    #[test]
+    fn test_append_repeats() {
+        let mut q = Query::new();
+        q.append("fields", "summary");
+        q.append("fields", "status");
+
+        assert_eq!(q.get("fields"), Some("summary"));
+        assert_eq!(q.get_all("fields"), vec!["summary", "status"]);
+    }
+
+    #[test]
+    fn test_extend_overrides_keys() {
+        let mut q = Query::new();
+        q.append("a", "1");
+        q.append("a", "2");
+        q.insert("b", "1");
+
+        let mut o = Query::new();
+        o.insert("a", "3");
+
+        q.extend(o);
+
+        assert_eq!(q.get_all("a"), vec!["3"]);
+        assert_eq!(q.get("b"), Some("1"));
+    }
+
+    #[test]
+    fn test_precondition() {
+        let p = Precondition::if_match("abc");
+
+        assert_eq!(p.if_match.as_deref(), Some("abc"));
+        assert_eq!(p.if_none_match, None);
+        assert!(!p.is_empty());
+        assert!(Precondition::default().is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+//! JIRA Cloud reports how much of the rate limit is left in the response
+//! headers, this module parses them so callers can back off before they are
+//! throttled.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Resp;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, PartialEq)]
+pub struct RateLimit {
+    /// Maximum number of requests allowed in the window, `X-RateLimit-Limit`
+    pub limit: Option<u64>,
+
+    /// Requests left in the current window, `X-RateLimit-Remaining`
+    pub remaining: Option<u64>,
+
+    /// When the window resets as an ISO 8601 timestamp, `X-RateLimit-Reset`
+    pub reset: Option<String>,
+
+    /// Seconds to wait before retrying, `Retry-After`. Usually only sent
+    /// along with a 429 or 503.
+    pub retry_after: Option<u64>,
+}
+
+impl RateLimit {
+    /// Parses the rate limit headers, returns None if none of them are
+    /// present
+    pub fn from_headers(headers: &HeaderMap) -> Option<RateLimit> {
+        let r = RateLimit {
+            limit: header_number(headers, "x-ratelimit-limit"),
+            remaining: header_number(headers, "x-ratelimit-remaining"),
+            reset: header_string(headers, "x-ratelimit-reset"),
+            retry_after: header_number(headers, RETRY_AFTER.as_str()),
+        };
+
+        let absent = r.limit.is_none()
+            && r.remaining.is_none()
+            && r.reset.is_none()
+            && r.retry_after.is_none();
+
+        if absent {
+            None
+        } else {
+            Some(r)
+        }
+    }
+}
+
+impl<D> Resp<D> {
+    /// Returns the rate limit reported with the response, None if JIRA did
+    /// not send any rate limit headers
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        RateLimit::from_headers(&self.headers)
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+fn header_string(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+}
+
+fn header_number(headers: &HeaderMap, name: &str) -> Option<u64> {
+    header_string(headers, name).and_then(|v| v.parse().ok())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("100"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("3"));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_static("2021-05-01T10:00Z"));
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("30"));
+
+        let resp = Resp { data: (), headers };
+
+        assert_eq!(
+            resp.rate_limit(),
+            Some(RateLimit {
+                limit: Some(100),
+                remaining: Some(3),
+                reset: Some("2021-05-01T10:00Z".to_string()),
+                retry_after: Some(30),
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_rate_limit_headers() {
+        let resp = Resp {
+            data: (),
+            headers: HeaderMap::new(),
+        };
+
+        assert!(resp.rate_limit().is_none());
+    }
+}
@@ -0,0 +1,89 @@
+//! Retrying requests that failed for transient reasons, e.g. a dropped
+//! connection or JIRA answering 503 while it restarts.
+
+// ============================================================================
+// Use
+// ============================================================================
+use reqwest::{Method, StatusCode};
+use std::time::Duration;
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+/// How often and how patiently failed requests are retried. Only requests
+/// that are safe to repeat are retried, that is every method except POST and
+/// PATCH unless the client is marked with `Client::retry_safe`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubled for every retry after that. A
+    /// `Retry-After` header sent by JIRA takes precedence.
+    pub backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, backoff: Duration) -> Self {
+        RetryPolicy {
+            max_retries,
+            backoff,
+        }
+    }
+
+    /// Returns how long to wait before the given retry, starting at 0
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<u64>) -> Duration {
+        match retry_after {
+            Some(secs) => Duration::from_secs(secs),
+            None => self.backoff * 2u32.saturating_pow(attempt),
+        }
+    }
+
+    /// Returns true if a response with the status is worth retrying
+    pub(crate) fn retryable_status(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// Returns true if sending the method twice has the same effect as
+    /// sending it once
+    pub(crate) fn idempotent(method: &Method) -> bool {
+        *method != Method::POST && *method != Method::PATCH
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(3, Duration::from_millis(500))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_doubles() {
+        let p = RetryPolicy::new(3, Duration::from_millis(100));
+
+        assert_eq!(p.delay(0, None), Duration::from_millis(100));
+        assert_eq!(p.delay(2, None), Duration::from_millis(400));
+        assert_eq!(p.delay(2, Some(5)), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_retryable() {
+        assert!(RetryPolicy::retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!RetryPolicy::retryable_status(StatusCode::BAD_REQUEST));
+        assert!(RetryPolicy::idempotent(&Method::PUT));
+        assert!(!RetryPolicy::idempotent(&Method::POST));
+    }
+}
@@ -0,0 +1,148 @@
+//! Optional request instrumentation, enabled with the `tracing` feature. When
+//! the feature is disabled everything in here compiles down to nothing.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Error;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+
+#[cfg(feature = "tracing")]
+use reqwest::header::{AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION};
+use std::time::Duration;
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+/// Tracks a single request from the moment it is sent until a response is
+/// received.
+pub(crate) struct RequestTrace {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+
+    #[cfg(feature = "tracing")]
+    started: Instant,
+}
+
+impl RequestTrace {
+    /// Opens a span for the request and emits an event with the outgoing
+    /// (redacted) headers. The query is left out of the endpoint as it may
+    /// carry secrets.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    #[inline]
+    pub fn start(method: &Method, endpoint: &str, headers: &HeaderMap) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let endpoint = without_query(endpoint);
+            let span = tracing::debug_span!("jira_request", %method, endpoint);
+            span.in_scope(|| {
+                tracing::trace!(headers = ?redact(headers), "sending request");
+            });
+
+            RequestTrace {
+                span,
+                started: Instant::now(),
+            }
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        RequestTrace {}
+    }
+
+    /// Emits the response status and the time elapsed since `start`.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    #[inline]
+    pub fn finish(&self, status: StatusCode) {
+        #[cfg(feature = "tracing")]
+        self.span.in_scope(|| {
+            tracing::debug!(
+                status = status.as_u16(),
+                elapsed_ms = self.started.elapsed().as_millis() as u64,
+                "received response"
+            );
+        });
+    }
+
+    /// Emits the error the request failed with before a response could be
+    /// read, and the time elapsed since `start`.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    #[inline]
+    pub fn fail(&self, error: &Error) {
+        #[cfg(feature = "tracing")]
+        self.span.in_scope(|| {
+            tracing::debug!(
+                error = %error.to_string().trim_end(),
+                elapsed_ms = self.started.elapsed().as_millis() as u64,
+                "request failed"
+            );
+        });
+    }
+
+    /// Emits an event when the request is about to be retried.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    #[inline]
+    pub fn retry(&self, attempt: u32, delay: Duration) {
+        #[cfg(feature = "tracing")]
+        self.span.in_scope(|| {
+            tracing::debug!(attempt, delay_ms = delay.as_millis() as u64, "retrying request");
+        });
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+/// Returns the endpoint without its query string.
+#[cfg(feature = "tracing")]
+fn without_query(endpoint: &str) -> &str {
+    endpoint.split('?').next().unwrap_or(endpoint)
+}
+
+/// Returns a printable copy of the headers with credentials masked out.
+#[cfg(feature = "tracing")]
+fn redact(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(k, v)| {
+            let value = if k == AUTHORIZATION || k == PROXY_AUTHORIZATION || k == COOKIE {
+                String::from("<redacted>")
+            } else {
+                v.to_str().unwrap_or("<binary>").to_string()
+            };
+
+            (k.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderValue, CONTENT_TYPE};
+
+    #[test]
+    fn test_redact_authorization() {
+        let mut h = HeaderMap::new();
+        h.insert(AUTHORIZATION, HeaderValue::from_static("Basic c2VjcmV0"));
+        h.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let r = redact(&h);
+
+        assert!(r.contains(&("authorization".to_string(), "<redacted>".to_string())));
+        assert!(r.contains(&("content-type".to_string(), "application/json".to_string())));
+    }
+
+    #[test]
+    fn test_without_query() {
+        let endpoint = "https://jira.example.com/rest/api/2/myself";
+
+        assert_eq!(without_query(&format!("{}?access_token=xyz", endpoint)), endpoint);
+        assert_eq!(without_query(endpoint), endpoint);
+    }
+}
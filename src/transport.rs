@@ -0,0 +1,84 @@
+//! The layer that carries built requests to JIRA. The client sends over the
+//! network by default, fixtures plug in here to record or replay exchanges
+//! without the rest of the client knowing about them.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Error;
+use crate::Result;
+use reqwest::header::HeaderMap;
+use reqwest::{Request, StatusCode};
+use std::fmt::Debug;
+use std::io::Read;
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+/// A response with its body read in full
+pub(crate) struct Reply {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// The HTTP backend of a client along with the limits it reads bodies with
+pub(crate) struct Network<'a> {
+    pub client: &'a reqwest::Client,
+    pub max_response_bytes: Option<u64>,
+}
+
+/// Sends requests straight over the network, the default transport
+#[derive(Debug)]
+pub(crate) struct Direct;
+
+// ============================================================================
+// Public Traits
+// ============================================================================
+/// Carries a request the client built, credentials included, and returns
+/// the response. `body` is the body the request was built with, which
+/// can't be read back from the request itself.
+pub(crate) trait Transport: Debug + Send + Sync {
+    fn execute(&self, net: &Network, req: Request, body: Option<&[u8]>) -> Result<Reply>;
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl Transport for Direct {
+    fn execute(&self, net: &Network, req: Request, _body: Option<&[u8]>) -> Result<Reply> {
+        net.send(req)
+    }
+}
+
+impl Network<'_> {
+    /// Sends a request and reads the response enforcing `max_response_bytes`
+    /// if set
+    pub fn send(&self, req: Request) -> Result<Reply> {
+        let mut res = self.client.execute(req)?;
+        let mut body = Vec::new();
+
+        match self.max_response_bytes {
+            Some(max) => {
+                if res.content_length().unwrap_or(0) > max {
+                    return Err(Error::ResponseTooLarge);
+                }
+
+                res.by_ref().take(max + 1).read_to_end(&mut body)?;
+
+                if body.len() as u64 > max {
+                    return Err(Error::ResponseTooLarge);
+                }
+            }
+            None => {
+                res.read_to_end(&mut body)?;
+            }
+        }
+
+        Ok(Reply {
+            status: res.status(),
+            headers: res.headers().clone(),
+            body,
+        })
+    }
+}
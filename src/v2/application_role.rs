@@ -3,28 +3,17 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
 use crate::Client;
+use crate::Precondition;
 use crate::Response;
 use crate::{Deserialize, Serialize};
 
 // ============================================================================
 // Public Structures
 // ============================================================================
-pub struct ApplicationRoleOptions {
-    /// The hash of the version to update. Optional Param.
-    ///
-    /// Optional: If versionHash is passed through the If-Match header the
-    /// request will be rejected if not the same as server
-    if_match: String,
-}
-
-impl ApplicationRoleOptions {
-    pub fn new(if_match: String) -> Self {
-        ApplicationRoleOptions { if_match }
-    }
-}
-
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ApplicationRole {
     /// Identifier of the application role
     #[serde(default)]
@@ -82,7 +71,7 @@ impl ApplicationRole {
     where
         K: Into<String>,
     {
-        let endpoint = format!("api/2/applicationrole/{}", key.into());
+        let endpoint = format!("{}/applicationrole/{}", Api::V2, encode_segment(&key.into()));
         c.get(&endpoint)
     }
 
@@ -90,49 +79,58 @@ impl ApplicationRole {
     /// docs:
     /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/applicationrole-getAll
     pub fn all(c: &Client) -> Response<Vec<Self>> {
-        c.get("api/2/applicationrole")
+        c.get(&Api::V2.endpoint("applicationrole"))
     }
 
-    /// Will bulk update roles given a vector of ApplicationRole. For more
-    /// detailed information see `update` below or consult the api docs:
+    /// Will bulk update roles given a vector of ApplicationRole, an If-Match
+    /// precondition rejects the update if the hash of the roles on the server
+    /// differs. For more detailed information see `update` below or consult
+    /// the api docs:
     /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/applicationrole-putBulk
     pub fn update_bulk(
         c: &Client,
         a: Vec<Self>,
-        o: Option<ApplicationRoleOptions>,
+        p: Option<Precondition>,
     ) -> Response<Vec<Self>> {
-        let mut c = c.clone();
-
-        if let Some(o) = o {
-            c.add_header("If-Match", o.if_match);
-        }
-
-        c.put("api/2/applicationrole", a)
+        c.clone()
+            .precondition(p)
+            .put(&Api::V2.endpoint("applicationrole"), a)
     }
 
     /// Updates the role with the information currently in the struct. Note
     /// that only certain fields can be updated here as per the API spec, the
-    /// others are silently ignored. See docs for more info:
+    /// others are silently ignored. An If-Match precondition holding the
+    /// version hash rejects the update if it differs from the server. See docs
+    /// for more info:
     /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/applicationrole-put
-    pub fn update(&self, c: &Client, o: Option<ApplicationRoleOptions>) -> Response<Self> {
-        let mut c = c.clone();
-        let endpoint = format!("api/2/applicationrole/{}", self.key);
-
-        if let Some(o) = o {
-            c.add_header("If-Match", o.if_match);
-        }
+    pub fn update(&self, c: &Client, p: Option<Precondition>) -> Response<Self> {
+        let endpoint = format!("{}/applicationrole/{}", Api::V2, encode_segment(&self.key));
+        c.clone().precondition(p).put(&endpoint, self)
+    }
 
-        c.put(&endpoint, self)
+    /// Returns the role as pretty printed JSON, which is what `Display` gave
+    /// before it printed a one line summary
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
     }
 }
 
 // ============================================================================
 // Trait Implementations
 // ============================================================================
+/// Prints a one line summary of the role and its seats in use, e.g.
+/// `ApplicationRole(jira-software) Jira Software [10/25 seats]`. See
+/// `to_json` for every field.
 impl std::fmt::Display for ApplicationRole {
     // This trait requires fmt with this signature
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+        write!(f, "ApplicationRole({}) {} [{}/", self.key, self.name, self.user_count)?;
+
+        if self.has_unlimited_seats {
+            write!(f, "unlimited seats]")
+        } else {
+            write!(f, "{} seats]", self.number_of_seats)
+        }
     }
 }
 
@@ -163,4 +161,21 @@ mod tests {
         assert_eq!(application_role.groups.len(), 2);
         assert_eq!(application_role.key, "jira-software");
     }
+
+    #[test]
+    fn test_display() {
+        let results = fs::read_to_string("tests/assets/v2/application_role.json")
+            .expect("Unable to read in JSON file");
+        let mut role: ApplicationRole = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(role.to_string(), "ApplicationRole(jira-software) JIRA Software [5/10 seats]");
+        assert!(role.to_json().contains("\"numberOfSeats\": 10"));
+
+        role.has_unlimited_seats = true;
+
+        assert_eq!(
+            role.to_string(),
+            "ApplicationRole(jira-software) JIRA Software [5/unlimited seats]"
+        );
+    }
 }
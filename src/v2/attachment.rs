@@ -3,7 +3,9 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
 use crate::v2::User;
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
@@ -61,9 +63,20 @@ impl Attachment {
     where
         I: Into<String>,
     {
-        let url = format!("api/2/attachment/{}", id.into());
+        let url = format!("{}/attachment/{}", Api::V2, encode_segment(&id.into()));
         c.get(&url)
     }
+
+    /// Downloads the content of the attachment, see `mime_type` for what it
+    /// holds
+    pub fn download(&self, c: &Client) -> Response<Vec<u8>> {
+        c.download(&self.content)
+    }
+
+    /// Downloads the thumbnail of the attachment, only images have one
+    pub fn download_thumbnail(&self, c: &Client) -> Response<Vec<u8>> {
+        c.download(&self.thumbnail)
+    }
 }
 
 // ============================================================================
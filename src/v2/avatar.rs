@@ -0,0 +1,162 @@
+//! Avatars for users and projects, also handles uploading custom avatars
+//! which JIRA splits into a temporary upload followed by a crop.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Client;
+use crate::Query;
+use crate::Resp;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+use reqwest::Method;
+use std::collections::BTreeMap;
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Avatar {
+    /// ID of the avatar
+    #[serde(default)]
+    pub id: String,
+
+    /// Username or project key owning a custom avatar
+    #[serde(default)]
+    pub owner: String,
+
+    /// Is the avatar one of the avatars shipped with JIRA
+    #[serde(rename = "isSystemAvatar", default)]
+    pub is_system_avatar: bool,
+
+    /// Is the avatar the one currently in use
+    #[serde(rename = "isSelected", default)]
+    pub is_selected: bool,
+
+    /// Can the avatar be deleted
+    #[serde(rename = "isDeletable", default)]
+    pub is_deletable: bool,
+
+    /// URLs to the avatar keyed by size e.g. "48x48"
+    #[serde(default)]
+    pub urls: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Avatars {
+    /// Avatars shipped with JIRA
+    #[serde(default)]
+    pub system: Vec<Avatar>,
+
+    /// Avatars uploaded by users
+    #[serde(default)]
+    pub custom: Vec<Avatar>,
+}
+
+/// The square area of an uploaded image used for the avatar, coordinates are
+/// in pixels from the top left of the image
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct AvatarCrop {
+    #[serde(rename = "cropperOffsetX", default)]
+    pub x: i64,
+
+    #[serde(rename = "cropperOffsetY", default)]
+    pub y: i64,
+
+    /// Width and height of the square
+    #[serde(rename = "cropperWidth", default)]
+    pub width: i64,
+}
+
+// ============================================================================
+// Crate Functions
+// ============================================================================
+/// Uploads an image as a custom avatar and selects it, `endpoint` is the
+/// avatar resource of the owner and `owner` any query arguments identifying
+/// them. When no crop is given the square JIRA suggests after the temporary
+/// upload is used, this covers as much of the image as possible.
+pub(crate) fn upload(
+    c: &Client,
+    endpoint: &str,
+    owner: Query,
+    image: Vec<u8>,
+    content_type: &str,
+    crop: Option<AvatarCrop>,
+) -> Response<String> {
+    #[derive(Deserialize)]
+    struct Temporary {
+        #[serde(flatten)]
+        crop: AvatarCrop,
+    }
+
+    #[derive(Serialize)]
+    struct Confirm {
+        #[serde(flatten)]
+        crop: AvatarCrop,
+        #[serde(rename = "needsCropping")]
+        needs_cropping: bool,
+    }
+
+    #[derive(Serialize)]
+    struct Select<'a> {
+        id: &'a str,
+    }
+
+    let mut query = owner.clone();
+    query.insert("filename", "avatar");
+    query.insert("size", image.len().to_string());
+
+    let mut uc = c.clone().add_query(query);
+    uc.add_header("content-type", content_type.to_string());
+    uc.add_header("x-atlassian-token", "no-check".to_string());
+
+    let temporary_endpoint = format!("{}/temporary", endpoint);
+    let Resp { data: temporary, .. } =
+        uc.request::<Temporary>(Method::POST, &temporary_endpoint, Some(image))?;
+
+    let body = Confirm {
+        crop: crop.unwrap_or(temporary.crop),
+        needs_cropping: false,
+    };
+    let c = c.clone().add_query(owner);
+    let Resp { data: avatar, .. } = c.post::<_, Avatar>(endpoint, body)?;
+    let Resp { headers, .. } = c.put::<_, ()>(endpoint, Select { id: &avatar.id })?;
+
+    Ok(Resp {
+        data: avatar.id,
+        headers,
+    })
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Avatar {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results =
+            fs::read_to_string("tests/assets/v2/avatar.json").expect("Unable to read in JSON file");
+        let a: Avatars = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(a.system.len(), 20);
+        assert!(a.system[0].is_system_avatar);
+        assert_eq!(a.custom[0].id, "15402");
+        assert_eq!(a.custom[0].owner, "silvios");
+        assert!(a.custom[0].is_selected);
+        assert_eq!(a.custom[0].urls.len(), 4);
+    }
+}
@@ -0,0 +1,89 @@
+//! Boards from the JIRA Software (Agile) API, boards display the issues from
+//! one or more projects and own the sprints planned against them.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::v2::Pagination;
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Board {
+    /// ID of the board
+    #[serde(default)]
+    pub id: i64,
+
+    /// REST API link to the board
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// Name of the board
+    #[serde(default)]
+    pub name: String,
+
+    /// Type of the board e.g. "scrum" or "kanban"
+    #[serde(rename = "type", default)]
+    pub board_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedBoards {
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// Boards on this page
+    #[serde(default)]
+    pub values: Vec<Board>,
+}
+
+impl Board {
+    /// Fetches a page of the boards visible to the current user. For more
+    /// info consult the api docs:
+    /// https://docs.atlassian.com/jira-software/REST/8.2.6/#agile/1.0/board-getAllBoards
+    pub fn all(c: &Client, page: Option<Pagination>) -> Response<PaginatedBoards> {
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let query = Client::unpack_options(vec![&page]);
+
+        c.clone().add_query(query).get(&Api::Agile.endpoint("board"))
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Board {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results =
+            fs::read_to_string("tests/assets/v2/boards.json").expect("Unable to read in JSON file");
+        let b: PaginatedBoards = serde_json::from_str(&results).unwrap();
+
+        assert!(b.pagination.is_last);
+        assert_eq!(b.pagination.total, 2);
+        assert_eq!(b.values.len(), 2);
+        assert_eq!(b.values[0].id, 84);
+        assert_eq!(b.values[0].name, "scrum board");
+        assert_eq!(b.values[1].board_type, "kanban");
+    }
+}
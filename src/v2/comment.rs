@@ -3,12 +3,74 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
 use crate::v2::{Pagination, User};
+use crate::Api;
+use crate::Client;
+use crate::Response;
 use crate::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+// ============================================================================
+// Public Enums
+// ============================================================================
+/// The body of a comment, version 2 of the API uses wiki markup while
+/// version 3 (JIRA Cloud only) uses the Atlassian Document Format.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum CommentBody {
+    /// Wiki markup e.g. "*bold* text"
+    Wiki(String),
+
+    /// An Atlassian Document Format document, see [`AdfDocument`] for
+    /// building simple documents
+    Adf(Value),
+}
+
+impl Default for CommentBody {
+    fn default() -> Self {
+        CommentBody::Wiki(String::new())
+    }
+}
 
 // ============================================================================
 // Public Structures
 // ============================================================================
+/// A minimal builder for Atlassian Document Format documents, for more info on
+/// the format consult the docs:
+/// https://developer.atlassian.com/cloud/jira/platform/apis/document/structure/
+#[derive(Debug, Default)]
+pub struct AdfDocument {
+    content: Vec<Value>,
+}
+
+impl AdfDocument {
+    pub fn new() -> Self {
+        AdfDocument::default()
+    }
+
+    /// Appends a paragraph of plain text to the document
+    pub fn paragraph<T>(mut self, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.content.push(json!({
+            "type": "paragraph",
+            "content": [{"type": "text", "text": text.into()}]
+        }));
+        self
+    }
+
+    /// Returns the document as JSON
+    pub fn to_value(&self) -> Value {
+        json!({
+            "type": "doc",
+            "version": 1,
+            "content": self.content
+        })
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Comment {
     /// REST API link to comment
@@ -25,7 +87,7 @@ pub struct Comment {
 
     /// Body of comment
     #[serde(default)]
-    pub body: String,
+    pub body: CommentBody,
 
     /// Author of last update to the comment
     #[serde(rename = "updateAuthor", default)]
@@ -52,9 +114,52 @@ pub struct PaginatedComments {
     pub pagination: Option<Pagination>
 }
 
+impl Comment {
+    /// Adds a comment to an issue. Wiki bodies are sent to version 2 of the
+    /// API and ADF bodies to version 3, posting a plain string to version 3
+    /// renders incorrectly. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-addComment
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v3/api-group-issue-comments/#api-rest-api-3-issue-issueidorkey-comment-post
+    pub fn add<K>(c: &Client, issue_key: K, body: CommentBody) -> Response<Comment>
+    where
+        K: Into<String>,
+    {
+        #[derive(Serialize)]
+        struct Request {
+            body: CommentBody,
+        }
+
+        let api = match body {
+            CommentBody::Wiki(_) => Api::V2,
+            CommentBody::Adf(_) => Api::V3,
+        };
+        let endpoint = format!("{}/issue/{}/comment", api, encode_segment(&issue_key.into()));
+
+        c.create(&endpoint, Request { body })
+    }
+}
+
 // ============================================================================
 // Trait Implementations
 // ============================================================================
+impl From<AdfDocument> for CommentBody {
+    fn from(doc: AdfDocument) -> Self {
+        CommentBody::Adf(doc.to_value())
+    }
+}
+
+impl From<String> for CommentBody {
+    fn from(text: String) -> Self {
+        CommentBody::Wiki(text)
+    }
+}
+
+impl From<&str> for CommentBody {
+    fn from(text: &str) -> Self {
+        CommentBody::Wiki(text.to_string())
+    }
+}
+
 impl std::fmt::Display for Comment {
     // This trait requires fmt with this signature
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -67,4 +172,40 @@ impl std::fmt::Display for PaginatedComments {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
     }
-}
\ No newline at end of file
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_adf_body() {
+        let results = fs::read_to_string("tests/assets/v2/comment_adf.json")
+            .expect("Unable to read in JSON file");
+        let comment: Comment = serde_json::from_str(&results).unwrap();
+        let expected: CommentBody = AdfDocument::new().paragraph("Looks good to me").into();
+
+        assert_eq!(comment.id, "10000");
+        assert_eq!(comment.body, expected);
+    }
+
+    #[test]
+    fn test_deserialize_wiki_body() {
+        let comment: Comment = serde_json::from_str(r#"{"id": "1", "body": "*done*"}"#).unwrap();
+
+        assert_eq!(comment.body, CommentBody::Wiki("*done*".to_string()));
+    }
+
+    #[test]
+    fn test_adf_paragraphs() {
+        let doc = AdfDocument::new().paragraph("one").paragraph("two").to_value();
+
+        assert_eq!(doc["type"], "doc");
+        assert_eq!(doc["version"], 1);
+        assert_eq!(doc["content"][1]["content"][0]["text"], "two");
+    }
+}
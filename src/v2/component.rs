@@ -3,8 +3,11 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
 use crate::v2::User;
+use crate::Api;
 use crate::Client;
+use crate::Query;
 use crate::Response;
 use crate::{Deserialize, Serialize};
 
@@ -70,9 +73,40 @@ impl Component {
     where
         T: Into<String>,
     {
-        let endpoint = format!("api/2/component/{}", id.into());
+        let endpoint = format!("{}/component/{}", Api::V2, encode_segment(&id.into()));
         c.get(&endpoint)
     }
+
+    /// Deletes a component by id, for more information consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/component-delete
+    pub fn delete<T>(c: &Client, id: T) -> Response<()>
+    where
+        T: Into<String>,
+    {
+        let endpoint = format!("{}/component/{}", Api::V2, encode_segment(&id.into()));
+        c.delete(&endpoint)
+    }
+
+    /// Deletes this component
+    pub fn delete_self(&self, c: &Client) -> Response<()> {
+        Component::delete(c, self.id.as_str())
+    }
+
+    /// Deletes a component by id moving the issues that have it to another
+    /// component, for more information consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/component-delete
+    pub fn delete_and_replace<T, M>(c: &Client, id: T, move_issues_to: M) -> Response<()>
+    where
+        T: Into<String>,
+        M: Into<String>,
+    {
+        let mut query = Query::new();
+
+        query.insert("moveIssuesTo", move_issues_to.into());
+
+        let endpoint = format!("{}/component/{}", Api::V2, encode_segment(&id.into()));
+        c.clone().add_query(query).delete(&endpoint)
+    }
 }
 
 // ============================================================================
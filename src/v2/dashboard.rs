@@ -0,0 +1,118 @@
+//! Dashboards collect gadgets for reporting, this module provides read only
+//! access to the dashboards visible to the current user.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_segment;
+use crate::v2::{Pagination, SharePermission, User};
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Dashboard {
+    /// REST API link to the dashboard
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// ID of the dashboard
+    #[serde(default)]
+    pub id: String,
+
+    /// Name of the dashboard
+    #[serde(default)]
+    pub name: String,
+
+    /// User that owns the dashboard
+    #[serde(default)]
+    pub owner: Option<User>,
+
+    /// Link to view the dashboard in the browser
+    #[serde(default)]
+    pub view: String,
+
+    /// Who the dashboard is shared with
+    #[serde(rename = "sharePermissions", default)]
+    pub share_permissions: Vec<SharePermission>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedDashboards {
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// REST API link to the previous page
+    #[serde(default)]
+    pub prev: String,
+
+    /// REST API link to the next page
+    #[serde(default)]
+    pub next: String,
+
+    /// Dashboards on this page
+    #[serde(default)]
+    pub dashboards: Vec<Dashboard>,
+}
+
+impl Dashboard {
+    /// Fetches a page of the dashboards visible to the current user. For more
+    /// info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/dashboard-list
+    pub fn all(c: &Client, page: Option<Pagination>) -> Response<PaginatedDashboards> {
+        let page = Pagination::checked(page, Pagination::MAX_DASHBOARDS)?;
+        let query = Client::unpack_options(vec![&page]);
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("dashboard"))
+    }
+
+    /// Fetches a dashboard given its id. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/dashboard-getDashboard
+    pub fn from_id<I>(c: &Client, id: I) -> Response<Dashboard>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/dashboard/{}", Api::V2, encode_segment(&id.into()));
+        c.get(&endpoint)
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Dashboard {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/dashboards.json")
+            .expect("Unable to read in JSON file");
+        let d: PaginatedDashboards = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(d.pagination.start_at, 10);
+        assert_eq!(d.pagination.max_results, 10);
+        assert_eq!(d.pagination.total, 143);
+        assert_eq!(d.dashboards.len(), 2);
+        assert_eq!(d.dashboards[0].id, "10000");
+        assert_eq!(d.dashboards[0].name, "System Dashboard");
+        assert_eq!(d.dashboards[0].share_permissions[0].share_type, "global");
+        assert_eq!(d.dashboards[1].owner.as_ref().unwrap().name, "fred");
+    }
+}
@@ -0,0 +1,175 @@
+//! Fields are the system and custom fields available on the instance, custom
+//! fields are referred to by an id such as "customfield_10010" that differs
+//! between instances so they are usually looked up by name.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Api;
+use crate::Client;
+use crate::Error;
+use crate::Response;
+use crate::Result;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+/// The custom field type of the Epic Link field on company-managed projects
+pub const EPIC_LINK_TYPE: &str = "com.pyxis.greenhopper.jira:gh-epic-link";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Field {
+    /// ID of the field e.g. "summary" or "customfield_10010"
+    #[serde(default)]
+    pub id: String,
+
+    /// Key of the field, the same as the id for most fields
+    #[serde(default)]
+    pub key: String,
+
+    /// Name of the field as shown to users
+    #[serde(default)]
+    pub name: String,
+
+    /// Is this a custom field
+    #[serde(default)]
+    pub custom: bool,
+
+    /// Can the field be used as a column in issue navigator
+    #[serde(default)]
+    pub navigable: bool,
+
+    /// Can the field be searched with JQL
+    #[serde(default)]
+    pub searchable: bool,
+
+    /// Names the field goes by in JQL
+    #[serde(rename = "clauseNames", default)]
+    pub clause_names: Vec<String>,
+
+    /// Type of the values of the field, missing for some system fields
+    #[serde(default)]
+    pub schema: Option<FieldSchema>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FieldSchema {
+    /// Type of the value e.g. "string", "array" or "user"
+    #[serde(rename = "type", default)]
+    pub field_type: String,
+
+    /// Type of the items of an array
+    #[serde(default)]
+    pub items: Option<String>,
+
+    /// ID of a system field
+    #[serde(default)]
+    pub system: Option<String>,
+
+    /// Type of a custom field e.g. "com.pyxis.greenhopper.jira:gh-epic-link"
+    #[serde(default)]
+    pub custom: Option<String>,
+
+    /// ID of a custom field
+    #[serde(rename = "customId", default)]
+    pub custom_id: Option<i64>,
+}
+
+impl Field {
+    /// Fetches every system and custom field on the instance. For more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/field-getFields
+    pub fn all(c: &Client) -> Response<Vec<Field>> {
+        c.get(&Api::V2.endpoint("field"))
+    }
+
+    /// Returns the field with the given name, names are matched ignoring
+    /// case. Fails with `Error::FieldNotFound` listing the names of the
+    /// fields on the instance if there isn't one.
+    pub fn by_name(c: &Client, name: &str) -> Result<Field> {
+        find(Field::all(c)?.data, |f| f.name.eq_ignore_ascii_case(name), name)
+    }
+
+    /// Returns the Epic Link custom field of company-managed projects, found
+    /// by its type so a renamed field is still found. Team-managed projects
+    /// set the epic through the parent instead.
+    pub fn epic_link(c: &Client) -> Result<Field> {
+        find(
+            Field::all(c)?.data,
+            |f| f.custom_type() == Some(EPIC_LINK_TYPE),
+            "Epic Link",
+        )
+    }
+
+    /// Returns the type of a custom field, None for system fields
+    pub fn custom_type(&self) -> Option<&str> {
+        self.schema.as_ref().and_then(|s| s.custom.as_deref())
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Field {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+fn find<P>(fields: Vec<Field>, predicate: P, name: &str) -> Result<Field>
+where
+    P: Fn(&Field) -> bool,
+{
+    let available = fields.iter().map(|f| f.name.clone()).collect();
+
+    fields
+        .into_iter()
+        .find(predicate)
+        .ok_or_else(|| Error::FieldNotFound {
+            name: name.to_string(),
+            available,
+        })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/fields.json")
+            .expect("Unable to read in JSON file");
+        let fields: Vec<Field> = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(fields.len(), 3);
+        assert!(!fields[0].custom);
+        assert_eq!(fields[0].custom_type(), None);
+        assert_eq!(fields[1].id, "customfield_10014");
+        assert_eq!(fields[1].custom_type(), Some(EPIC_LINK_TYPE));
+        assert_eq!(fields[2].schema.as_ref().unwrap().custom_id, Some(10020));
+    }
+
+    #[test]
+    fn test_find() {
+        let results = fs::read_to_string("tests/assets/v2/fields.json")
+            .expect("Unable to read in JSON file");
+        let fields: Vec<Field> = serde_json::from_str(&results).unwrap();
+
+        match find(fields, |f| f.name == "Sprint", "Sprint") {
+            Err(Error::FieldNotFound { name, available }) => {
+                assert_eq!(name, "Sprint");
+                assert_eq!(available, vec!["Summary", "Epic Link", "Story Points"]);
+            }
+            r => panic!("expected FieldNotFound, got {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Field configurations control whether fields are required or hidden, they
+//! are applied to projects through field configuration schemes. Reading them
+//! requires the Administer Jira global permission.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::v2::Pagination;
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FieldConfiguration {
+    /// ID of the field configuration
+    #[serde(default)]
+    pub id: i64,
+
+    /// Name of the field configuration
+    #[serde(default)]
+    pub name: String,
+
+    /// Description of the field configuration
+    #[serde(default)]
+    pub description: String,
+
+    /// Is this the default field configuration
+    #[serde(rename = "isDefault", default)]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedFieldConfigurations {
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// Field configurations on this page
+    #[serde(default)]
+    pub values: Vec<FieldConfiguration>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FieldConfigurationScheme {
+    /// ID of the field configuration scheme
+    #[serde(default)]
+    pub id: String,
+
+    /// Name of the field configuration scheme
+    #[serde(default)]
+    pub name: String,
+
+    /// Description of the field configuration scheme
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A field configuration scheme along with the projects that use it, the
+/// scheme is None for projects using the default field configuration
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FieldConfigurationSchemeProjects {
+    /// The field configuration scheme
+    #[serde(rename = "fieldConfigurationScheme", default)]
+    pub field_configuration_scheme: Option<FieldConfigurationScheme>,
+
+    /// IDs of the projects using the scheme
+    #[serde(rename = "projectIds", default)]
+    pub project_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedFieldConfigurationSchemeProjects {
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// Schemes and their projects on this page
+    #[serde(default)]
+    pub values: Vec<FieldConfigurationSchemeProjects>,
+}
+
+impl FieldConfiguration {
+    /// Fetches a page of the field configurations on the instance, a user
+    /// without admin rights gets `Error::Forbidden`. For more info consult
+    /// the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-issue-field-configurations/#api-rest-api-2-fieldconfiguration-get
+    pub fn all(c: &Client, page: Option<Pagination>) -> Response<PaginatedFieldConfigurations> {
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let query = Client::unpack_options(vec![&page]);
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("fieldconfiguration"))
+    }
+
+    /// Fetches a page of the field configuration schemes used by the given
+    /// projects along with the projects using each scheme. For more info
+    /// consult the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-issue-field-configurations/#api-rest-api-2-fieldconfigurationscheme-project-get
+    pub fn schemes_for_projects<P>(
+        c: &Client,
+        project_ids: &[P],
+        page: Option<Pagination>,
+    ) -> Response<PaginatedFieldConfigurationSchemeProjects>
+    where
+        P: AsRef<str>,
+    {
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let mut query = Client::unpack_options(vec![&page]);
+
+        for id in project_ids {
+            query.append("projectId", id.as_ref());
+        }
+
+        c.clone()
+            .add_query(query)
+            .get(&Api::V2.endpoint("fieldconfigurationscheme/project"))
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for FieldConfiguration {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/field_configurations.json")
+            .expect("Unable to read in JSON file");
+        let f: PaginatedFieldConfigurations = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(f.pagination.total, 2);
+        assert_eq!(f.values.len(), 2);
+        assert!(f.values[0].is_default);
+        assert_eq!(f.values[1].id, 10001);
+        assert_eq!(f.values[1].name, "My Field Configuration");
+    }
+}
@@ -0,0 +1,215 @@
+//! Saved JQL filters, filters can be shared with other users and run through
+//! the search API.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_segment;
+use crate::v2::{Group, Pagination, Project, Search, SearchResults, User};
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SharePermission {
+    /// ID of the share permission
+    #[serde(default)]
+    pub id: i64,
+
+    /// Who the filter is shared with e.g. "global", "project" or "group"
+    #[serde(rename = "type", default)]
+    pub share_type: String,
+
+    /// The project the filter is shared with when the type is "project"
+    #[serde(default)]
+    pub project: Option<Project>,
+
+    /// The group the filter is shared with when the type is "group"
+    #[serde(default)]
+    pub group: Option<Group>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Filter {
+    /// REST API link to the filter
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// ID of the filter
+    #[serde(default)]
+    pub id: String,
+
+    /// Name of the filter
+    #[serde(default)]
+    pub name: String,
+
+    /// Description of the filter
+    #[serde(default)]
+    pub description: String,
+
+    /// User that owns the filter
+    #[serde(default)]
+    pub owner: Option<User>,
+
+    /// The JQL query the filter runs
+    #[serde(default)]
+    pub jql: String,
+
+    /// Link to view the filter in the browser
+    #[serde(rename = "viewUrl", default)]
+    pub view_url: String,
+
+    /// REST API link to run the filter
+    #[serde(rename = "searchUrl", default)]
+    pub search_url: String,
+
+    /// Is the filter a favourite of the current user
+    #[serde(default)]
+    pub favourite: bool,
+
+    /// Who the filter is shared with
+    #[serde(rename = "sharePermissions", default)]
+    pub share_permissions: Vec<SharePermission>,
+}
+
+/// The fields required to create or update a filter
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NewFilter {
+    /// Name of the filter
+    pub name: String,
+
+    /// Description of the filter
+    #[serde(default)]
+    pub description: String,
+
+    /// The JQL query the filter runs
+    pub jql: String,
+
+    /// Mark the filter as a favourite of the current user
+    #[serde(default)]
+    pub favourite: bool,
+}
+
+impl NewFilter {
+    pub fn new<N, J>(name: N, jql: J) -> Self
+    where
+        N: Into<String>,
+        J: Into<String>,
+    {
+        NewFilter {
+            name: name.into(),
+            jql: jql.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Filter {
+    /// Fetches a filter given its id. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/filter-getFilter
+    pub fn from_id<I>(c: &Client, id: I) -> Response<Filter>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/filter/{}", Api::V2, encode_segment(&id.into()));
+        c.get(&endpoint)
+    }
+
+    /// Creates a new filter. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/filter-createFilter
+    pub fn create(c: &Client, new_filter: NewFilter) -> Response<Filter> {
+        c.create(&Api::V2.endpoint("filter"), new_filter)
+    }
+
+    /// Updates the filter with the name, description, jql and favourite
+    /// values currently in the struct. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/filter-editFilter
+    pub fn update(&self, c: &Client) -> Response<Filter> {
+        let endpoint = format!("{}/filter/{}", Api::V2, encode_segment(&self.id));
+        let body = NewFilter {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            jql: self.jql.clone(),
+            favourite: self.favourite,
+        };
+
+        c.put(&endpoint, body)
+    }
+
+    /// Deletes a filter given its id. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/filter-deleteFilter
+    pub fn delete<I>(c: &Client, id: I) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/filter/{}", Api::V2, encode_segment(&id.into()));
+        c.delete(&endpoint)
+    }
+
+    /// Deletes this filter
+    pub fn delete_self(&self, c: &Client) -> Response<()> {
+        Filter::delete(c, self.id.as_str())
+    }
+
+    /// Fetches the filters owned by the current user. For more info consult
+    /// the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-filters/#api-rest-api-2-filter-my-get
+    pub fn my(c: &Client) -> Response<Vec<Filter>> {
+        c.get(&Api::V2.endpoint("filter/my"))
+    }
+
+    /// Fetches the filters the current user has marked as favourite. For more
+    /// info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/filter-getFavouriteFilters
+    pub fn favourites(c: &Client) -> Response<Vec<Filter>> {
+        c.get(&Api::V2.endpoint("filter/favourite"))
+    }
+
+    /// Runs the filter's JQL through the search API returning a page of
+    /// matching issues.
+    pub fn run(c: &Client, filter: &Filter, page: Option<Pagination>) -> Response<SearchResults> {
+        Search::jql(c, filter.jql.as_str(), page)
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Filter {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results =
+            fs::read_to_string("tests/assets/v2/filter.json").expect("Unable to read in JSON file");
+        let f: Filter = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(f.id, "10000");
+        assert_eq!(f.name, "All Open Bugs");
+        assert_eq!(f.owner.unwrap().name, "fred");
+        assert_eq!(f.jql, "type = Bug and resolution is empty");
+        assert!(f.favourite);
+        assert_eq!(f.share_permissions.len(), 3);
+        assert_eq!(f.share_permissions[1].project.as_ref().unwrap().key, "EX");
+        assert_eq!(
+            f.share_permissions[2].group.as_ref().unwrap().name,
+            "jira-administrators"
+        );
+    }
+}
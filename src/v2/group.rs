@@ -1,15 +1,16 @@
-//! Represents a paginated list of users by group
+//! Groups of users, this module lists their members and manages the groups
 
 // ============================================================================
 // Use
 // ============================================================================
 use crate::v2::Pagination;
 use crate::v2::User;
+use crate::Api;
 use crate::Client;
 use crate::Options;
+use crate::Query;
 use crate::Response;
 use crate::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 // ============================================================================
 // Public Structures
@@ -20,8 +21,8 @@ pub struct GroupOptions {
 }
 
 impl Options for GroupOptions {
-    fn to_query(&self) -> HashMap<String, String> {
-        let mut h = HashMap::new();
+    fn to_query(&self) -> Query {
+        let mut h = Query::new();
         h.insert(
             String::from("includeInactiveUsers"),
             self.include_inactive_users.to_string(),
@@ -38,7 +39,7 @@ impl Default for GroupOptions {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Group {
     /// REST API link to group
     #[serde(rename = "self", default)]
@@ -57,7 +58,88 @@ pub struct Group {
     pub name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GroupSuggestion {
+    /// Name of the group
+    #[serde(default)]
+    pub name: String,
+
+    /// Name of the group with the matching part wrapped in <b> tags
+    #[serde(default)]
+    pub html: String,
+
+    /// ID of the group, only returned by JIRA Cloud
+    #[serde(rename = "groupId", default)]
+    pub group_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GroupSuggestions {
+    /// Describes how many groups matched e.g. "Showing 2 of 2 matching groups"
+    #[serde(default)]
+    pub header: String,
+
+    /// Number of groups matching the query
+    #[serde(default)]
+    pub total: i64,
+
+    /// Matching groups
+    #[serde(default)]
+    pub groups: Vec<GroupSuggestion>,
+}
+
 impl Group {
+    /// Creates a group. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/group-createGroup
+    pub fn create<G>(c: &Client, name: G) -> Response<Group>
+    where
+        G: Into<String>,
+    {
+        #[derive(Serialize)]
+        struct Request {
+            name: String,
+        }
+
+        c.create(&Api::V2.endpoint("group"), Request { name: name.into() })
+    }
+
+    /// Deletes a group, comments and worklogs restricted to the group are
+    /// moved to `swap_group` when given. JIRA refuses to delete a group that
+    /// restricts comments or worklogs without one. For more info consult the
+    /// api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/group-removeGroup
+    pub fn delete<G>(c: &Client, name: G, swap_group: Option<String>) -> Response<()>
+    where
+        G: Into<String>,
+    {
+        let mut query = Query::new();
+
+        query.insert("groupname", name.into());
+        if let Some(swap) = swap_group {
+            query.insert("swapGroup", swap);
+        }
+
+        c.clone().add_query(query).delete(&Api::V2.endpoint("group"))
+    }
+
+    /// Finds groups whose name contains the query, JIRA returns at most
+    /// `max_results` groups and doesn't page through the rest. For more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/groups-findGroups
+    pub fn find<Q>(c: &Client, query: Q, max_results: Option<i64>) -> Response<GroupSuggestions>
+    where
+        Q: Into<String>,
+    {
+        let mut q = Query::new();
+
+        q.insert("query", query.into());
+        if let Some(max) = max_results {
+            q.insert("maxResults", max.to_string());
+        }
+
+        c.clone().add_query(q).get(&Api::V2.endpoint("groups/picker"))
+    }
+
     /// Fetches a paginated list of users inside a given group name. For more
     /// info consult the api docs:
     /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/group-getUsersFromGroup
@@ -71,13 +153,13 @@ impl Group {
         G: Into<String>,
     {
         let mut c = c.clone();
-        let mut query =
-            Client::unpack_options(vec![&opts.unwrap_or_default(), &page.unwrap_or_default()]);
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let mut query = Client::unpack_options(vec![&opts.unwrap_or_default(), &page]);
 
         query.insert("groupname".to_string(), name.into());
 
         c = c.add_query(query);
-        c.get("api/2/group/member")
+        c.get(&Api::V2.endpoint("group/member"))
     }
 }
 
@@ -8,16 +8,25 @@
 // ============================================================================
 // Use
 // ============================================================================
-use crate::v2::{Attachment, Component, Changelog, IssueType, IssueLink};
-use crate::v2::{PaginatedComments, PaginatedWorklog, Pagination, Priority};
-use crate::v2::{Progress, Project, Resolution, Status, TimeTracking, User};
-use crate::v2::{Version, Vote, Watches};
+use crate::client::encode_segment;
+use crate::v2::{Attachment, Component, Changelog, Field, IssueType, IssueLink};
+use crate::v2::{PaginatedComments, PaginatedWorklog, Pagination, Priority, ProjectContext};
+use crate::v2::{Progress, Project, Resolution, SecurityLevel, Status, TimeTracking, User};
+use crate::v2::{BulkTransitionOptions, IssueTransition, Notification, Transition, TransitionRef};
+use crate::v2::{Transitions, Version, Vote, Watches};
+use crate::Api;
 use crate::Client;
+use crate::Error;
+use crate::Precondition;
+use crate::Resp;
 use crate::Response;
+use crate::Result;
 use crate::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use crate::Query;
 use crate::QueryOptions;
-use std::collections::BTreeMap;
-use std::collections::HashMap;
+use reqwest::Method;
+use std::collections::{BTreeMap, HashMap};
 
 // ============================================================================
 // Public Enums
@@ -51,10 +60,68 @@ impl QueryOptions for IssueExpand {
 // ============================================================================
 // Public Structures
 // ============================================================================
+/// The fields to return with an issue, JIRA sends every field when fetching
+/// a single issue and the navigable fields when searching unless told
+/// otherwise. Leaving out large fields such as `comment` and `worklog` keeps
+/// big exports small.
+///
+/// Examples:
+/// Fields::All - include all fields
+/// Fields::Navigable - include just navigable fields
+/// Fields::only(&["summary", "comment"]) - include just the summary and comments
+/// Fields::exclude(&["comment"]) - include everything except comments
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fields {
+    /// Every field, `*all`
+    All,
+
+    /// The fields shown in the issue navigator, `*navigable`
+    Navigable,
+
+    /// Exactly these fields, which may also be JIRA's own tokens such as
+    /// `*navigable` or `-comment` as they are sent verbatim
+    Only(Vec<String>),
+
+    /// Every field except these
+    Exclude(Vec<String>),
+}
+
+impl Fields {
+    /// Returns only the given fields
+    pub fn only<F>(fields: &[F]) -> Self
+    where
+        F: AsRef<str>,
+    {
+        Fields::Only(fields.iter().map(|f| f.as_ref().to_string()).collect())
+    }
+
+    /// Returns every field except the given ones
+    pub fn exclude<F>(fields: &[F]) -> Self
+    where
+        F: AsRef<str>,
+    {
+        Fields::Exclude(fields.iter().map(|f| f.as_ref().to_string()).collect())
+    }
+
+    /// Returns the tokens JIRA expects, e.g. `["*all", "-comment"]`
+    pub fn to_list(&self) -> Vec<String> {
+        match self {
+            Fields::All => vec!["*all".to_string()],
+            Fields::Navigable => vec!["*navigable".to_string()],
+            Fields::Only(fields) => fields.clone(),
+            Fields::Exclude(fields) => std::iter::once("*all".to_string())
+                .chain(fields.iter().map(|f| format!("-{}", f)))
+                .collect(),
+        }
+    }
+}
+
 /// You should instantiate this struct with a list of strings which represent
 /// field names, to signify you want to include the field just supply the field
-/// name - to exclude the field place a '-' in front of the field name.alloc
-/// 
+/// name - to exclude the field place a '-' in front of the field name. The
+/// names are sent verbatim so JIRA's tokens can be used, or build one from
+/// `Fields`.
+///
 /// Examples:
 /// *all - include all fields
 /// *navigable - include just navigable fields
@@ -63,12 +130,28 @@ impl QueryOptions for IssueExpand {
 /// *all,-comment - include everything except comments
 pub struct IssueFieldOptions(Vec<String>);
 
+impl IssueFieldOptions {
+    /// Returns options including or excluding the given fields
+    pub fn new<F>(fields: &[F]) -> Self
+    where
+        F: AsRef<str>,
+    {
+        IssueFieldOptions(fields.iter().map(|f| f.as_ref().to_string()).collect())
+    }
+}
+
 impl Default for IssueFieldOptions {
     fn default() -> Self {
         IssueFieldOptions(vec!["*all".to_string()])
     }
 }
 
+impl From<Fields> for IssueFieldOptions {
+    fn from(fields: Fields) -> Self {
+        IssueFieldOptions(fields.to_list())
+    }
+}
+
 impl QueryOptions for IssueFieldOptions {
     fn to_string(&self) -> String {
         let IssueFieldOptions(l) = &self;
@@ -122,6 +205,342 @@ impl QueryOptions for IssueUpdateHistory {
     }
 }
 
+/// Describes a set of field operations to apply to an issue, unlike sending a
+/// full set of fields each operation is applied server side so concurrent
+/// edits which touch different values do not clobber each other.
+///
+/// Examples:
+/// IssueEdit::new().add_label("urgent").set_summary("New summary")
+/// IssueEdit::new().add("fixVersions", json!({"name": "1.0"}))
+/// IssueEdit::new().set_assignee(Some("fred")).notify_users(false)
+#[derive(Serialize, Debug, Default)]
+pub struct IssueEdit {
+    /// Operations keyed by field id
+    update: BTreeMap<String, Vec<::serde_json::Value>>,
+
+    /// Sent as `notifyUsers` rather than in the body
+    #[serde(skip)]
+    notify_users: Option<bool>,
+}
+
+impl IssueEdit {
+    /// Returns an edit with no operations
+    pub fn new() -> Self {
+        IssueEdit::default()
+    }
+
+    /// Adds a value to a multi-value field such as labels or components
+    pub fn add<F, V>(self, field: F, value: V) -> Self
+    where
+        F: Into<String>,
+        V: Into<::serde_json::Value>,
+    {
+        self.operation("add", field.into(), value.into())
+    }
+
+    /// Removes a value from a multi-value field such as labels or components
+    pub fn remove<F, V>(self, field: F, value: V) -> Self
+    where
+        F: Into<String>,
+        V: Into<::serde_json::Value>,
+    {
+        self.operation("remove", field.into(), value.into())
+    }
+
+    /// Replaces the value of a field
+    pub fn set<F, V>(self, field: F, value: V) -> Self
+    where
+        F: Into<String>,
+        V: Into<::serde_json::Value>,
+    {
+        self.operation("set", field.into(), value.into())
+    }
+
+    /// Adds a label to the issue
+    pub fn add_label<L>(self, label: L) -> Self
+    where
+        L: Into<String>,
+    {
+        self.add("labels", label.into())
+    }
+
+    /// Removes a label from the issue
+    pub fn remove_label<L>(self, label: L) -> Self
+    where
+        L: Into<String>,
+    {
+        self.remove("labels", label.into())
+    }
+
+    /// Adds a component to the issue by name
+    pub fn add_component<N>(self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.add("components", ::serde_json::json!({ "name": name.into() }))
+    }
+
+    /// Removes a component from the issue by name
+    pub fn remove_component<N>(self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.remove("components", ::serde_json::json!({ "name": name.into() }))
+    }
+
+    /// Sets the summary of the issue
+    pub fn set_summary<S>(self, summary: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.set("summary", summary.into())
+    }
+
+    /// Sets the security level of the issue given the id of the level, see
+    /// `SecurityLevel::for_project`
+    pub fn set_security<I>(self, level_id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.set("security", ::serde_json::json!({ "id": level_id.into() }))
+    }
+
+    /// Sets the assignee of the issue given their username, None leaves the
+    /// issue unassigned
+    pub fn set_assignee<U>(self, username: Option<U>) -> Self
+    where
+        U: Into<String>,
+    {
+        let assignee = username.map(|u| ::serde_json::json!({ "name": u.into() }));
+        self.set("assignee", assignee.unwrap_or(::serde_json::Value::Null))
+    }
+
+    /// Whether watchers are emailed about the edit, JIRA notifies them unless
+    /// told otherwise. Turning it off needs admin or project admin rights and
+    /// keeps bulk edits from flooding inboxes.
+    pub fn notify_users(mut self, notify: bool) -> Self {
+        self.notify_users = Some(notify);
+        self
+    }
+
+    /// Returns true if no operations have been added
+    pub fn is_empty(&self) -> bool {
+        self.update.is_empty()
+    }
+
+    fn operation(mut self, op: &str, field: String, value: ::serde_json::Value) -> Self {
+        self.update
+            .entry(field)
+            .or_default()
+            .push(::serde_json::json!({ op: value }));
+
+        self
+    }
+}
+
+/// The fields of an issue to create keyed by field id, this serializes to
+/// the `{"fields": {...}}` body JIRA expects. Project, issue type and summary
+/// are required by every project, anything else depends on its create
+/// screen.
+///
+/// Examples:
+/// NewIssue::new("10000", "10002", "Fix login").set("labels", json!(["auth"]))
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct NewIssue {
+    /// Field values keyed by field id
+    pub fields: BTreeMap<String, ::serde_json::Value>,
+}
+
+impl NewIssue {
+    /// Returns an issue with the project, issue type and summary set given
+    /// the id of the project and issue type
+    pub fn new<P, T, S>(project_id: P, issue_type_id: T, summary: S) -> Self
+    where
+        P: Into<String>,
+        T: Into<String>,
+        S: Into<String>,
+    {
+        NewIssue::default()
+            .set("project", ::serde_json::json!({ "id": project_id.into() }))
+            .set("issuetype", ::serde_json::json!({ "id": issue_type_id.into() }))
+            .set("summary", summary.into())
+    }
+
+    /// Sets the value of a field, replacing any value already set
+    pub fn set<F, V>(mut self, field: F, value: V) -> Self
+    where
+        F: Into<String>,
+        V: Into<::serde_json::Value>,
+    {
+        self.fields.insert(field.into(), value.into());
+        self
+    }
+
+    /// Returns a builder for an issue with setters for the common fields
+    pub fn builder() -> NewIssueBuilder {
+        NewIssueBuilder::default()
+    }
+}
+
+/// Builds a `NewIssue` field by field, `build` fails with
+/// `Error::MissingField` unless the project, issue type and summary are set.
+/// Projects and issue types given as a number are sent as an id, anything
+/// else as a key or name.
+///
+/// Examples:
+/// NewIssue::builder().project("EX").issue_type("Bug").summary("Crash").build()
+/// NewIssue::builder().project("10000").issue_type("1").custom("customfield_10010", json!(5))
+#[derive(Debug, Default)]
+pub struct NewIssueBuilder {
+    issue: NewIssue,
+    components: Vec<::serde_json::Value>,
+}
+
+impl NewIssueBuilder {
+    /// Sets the project given its key or id
+    pub fn project<P>(self, key_or_id: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.set_ref("project", "key", key_or_id.into())
+    }
+
+    /// Sets the issue type given its name or id
+    pub fn issue_type<T>(self, name_or_id: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.set_ref("issuetype", "name", name_or_id.into())
+    }
+
+    /// Sets the summary
+    pub fn summary<S>(self, summary: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.custom("summary", summary.into())
+    }
+
+    /// Sets the description
+    pub fn description<D>(self, description: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.custom("description", description.into())
+    }
+
+    /// Sets the assignee given their username
+    pub fn assignee<U>(self, username: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.custom("assignee", ::serde_json::json!({ "name": username.into() }))
+    }
+
+    /// Sets the priority given its name
+    pub fn priority<P>(self, name: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.custom("priority", ::serde_json::json!({ "name": name.into() }))
+    }
+
+    /// Sets the labels, replacing any labels already set
+    pub fn labels<L>(self, labels: Vec<L>) -> Self
+    where
+        L: Into<String>,
+    {
+        let labels: Vec<String> = labels.into_iter().map(Into::into).collect();
+        self.custom("labels", labels)
+    }
+
+    /// Adds a component given its name, may be called more than once
+    pub fn component<C>(mut self, name: C) -> Self
+    where
+        C: Into<String>,
+    {
+        self.components.push(::serde_json::json!({ "name": name.into() }));
+        self
+    }
+
+    /// Sets the parent issue given its key or id, making the issue a subtask
+    /// or on team-managed projects a child of an epic. See
+    /// `Issue::create_subtask` to check the parent before creating it.
+    pub fn parent<P>(self, key_or_id: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.set_ref("parent", "key", key_or_id.into())
+    }
+
+    /// Sets the epic of an issue on company-managed projects given the Epic
+    /// Link field found by `Field::epic_link` and the key of the epic
+    pub fn epic_link<E>(self, field: &Field, epic_key: E) -> Self
+    where
+        E: Into<String>,
+    {
+        self.custom(field.id.as_str(), epic_key.into())
+    }
+
+    /// Sets any field given its id, e.g. "customfield_10010"
+    pub fn custom<F, V>(mut self, field: F, value: V) -> Self
+    where
+        F: Into<String>,
+        V: Into<::serde_json::Value>,
+    {
+        self.issue = self.issue.set(field, value);
+        self
+    }
+
+    /// Returns the issue, fails with `Error::MissingField` naming the first
+    /// required field that wasn't set
+    pub fn build(self) -> Result<NewIssue> {
+        for field in &["project", "issuetype", "summary"] {
+            if !self.issue.fields.contains_key(*field) {
+                return Err(Error::MissingField(field.to_string()));
+            }
+        }
+
+        if self.components.is_empty() {
+            Ok(self.issue)
+        } else {
+            Ok(self.issue.set("components", self.components))
+        }
+    }
+
+    /// Sets a field referencing another entity by id when given a number and
+    /// by `other` otherwise
+    fn set_ref(self, field: &str, other: &str, value: String) -> Self {
+        let numeric = !value.is_empty() && value.chars().all(|c| c.is_ascii_digit());
+        let by = if numeric { "id" } else { other };
+        let mut reference = ::serde_json::Map::new();
+
+        reference.insert(by.to_string(), value.into());
+        self.custom(field, reference)
+    }
+}
+
+/// The reference to an issue JIRA returns once it has been created
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CreatedIssue {
+    /// The internal id of the ticket
+    #[serde(default)]
+    pub id: String,
+
+    /// The issue key in the format XXX-123
+    #[serde(default)]
+    pub key: String,
+
+    /// A link to the issue
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+}
+
+/// The fields of an issue. Only the fields asked for are returned, e.g. a
+/// search with `fields=summary` leaves out the status and project, so the
+/// members JIRA can omit stay `Option` and the `Issue` accessors pass that
+/// on rather than inventing empty values. Fields without a member, such as
+/// custom fields, are kept in `others`.
 #[derive(Deserialize, Serialize, Debug)]
 pub struct IssueFields {
     /// The issue type
@@ -160,6 +579,11 @@ pub struct IssueFields {
     #[serde(default)]
     pub resolution: Option<Resolution>,
 
+    /// Security level of the issue, None when the issue has no level or the
+    /// current user isn't allowed to see it
+    #[serde(default)]
+    pub security: Option<SecurityLevel>,
+
     /// Time tracking information (time spent, overall estimate etc.)
     #[serde(default)]
     pub timetracking: Option<TimeTracking>,
@@ -202,6 +626,10 @@ pub struct IssueFields {
     #[serde(default)]
     pub subtasks: Vec<Issue>,
 
+    /// The issue this is a subtask or child of
+    #[serde(default)]
+    pub parent: Option<Box<Issue>>,
+
     /// Date the issue was created in format: "2020-03-08T14:49:58.599+0000"
     #[serde(default)]
     pub created: String,
@@ -275,7 +703,7 @@ pub struct IssueFields {
     #[serde(default)]
     pub status: Option<Status>,
 
-    /// Flatten
+    /// Every field without a member above, keyed by field id
     #[serde(default, flatten)]
     pub others: BTreeMap<String, ::serde_json::Value>
 }
@@ -306,9 +734,109 @@ pub struct Issue {
     /// A chronical of the changes made to the issue.
     #[serde(default)]
     pub changelog: Option<Changelog>,
+
+    /// HTML rendered by JIRA for fields holding wiki markup keyed by field id,
+    /// only returned when fetched with `IssueExpand::RenderedFields`
+    #[serde(rename = "renderedFields", default)]
+    pub rendered_fields: BTreeMap<String, ::serde_json::Value>,
 }
 
 impl Issue {
+    /// Returns the rendered HTML of a field e.g. "description", None if the
+    /// issue wasn't fetched with `IssueExpand::RenderedFields` or the field
+    /// has no rendered value
+    pub fn rendered(&self, field: &str) -> Option<&str> {
+        self.rendered_fields.get(field).and_then(|v| v.as_str())
+    }
+
+    /// Returns the summary of the issue
+    pub fn summary(&self) -> &str {
+        self.fields.summary.as_str()
+    }
+
+    /// Returns the status of the issue, None if it wasn't among the fields
+    /// requested
+    pub fn status(&self) -> Option<&Status> {
+        self.fields.status.as_ref()
+    }
+
+    /// Returns the assignee, None if the issue is unassigned
+    pub fn assignee(&self) -> Option<&User> {
+        self.fields.assignee.as_ref()
+    }
+
+    /// Returns the reporter, None if the issue has none
+    pub fn reporter(&self) -> Option<&User> {
+        self.fields.reporter.as_ref()
+    }
+
+    /// Returns the priority, None if the issue has none
+    pub fn priority(&self) -> Option<&Priority> {
+        self.fields.priority.as_ref()
+    }
+
+    /// Returns the issue type, None if it wasn't among the fields requested
+    pub fn issue_type(&self) -> Option<&IssueType> {
+        self.fields.issue_type.as_ref()
+    }
+
+    /// Returns the project the issue is in, None if it wasn't among the
+    /// fields requested
+    pub fn project(&self) -> Option<&Project> {
+        self.fields.project.as_ref()
+    }
+
+    /// Returns the labels of the issue
+    pub fn labels(&self) -> &[String] {
+        self.fields.labels.as_slice()
+    }
+
+    /// Returns when the issue was created in the format:
+    /// "2020-03-10T16:27:20.772+0000"
+    pub fn created(&self) -> &str {
+        self.fields.created.as_str()
+    }
+
+    /// Returns when the issue was last updated in the format:
+    /// "2020-03-10T16:27:20.772+0000"
+    pub fn updated(&self) -> &str {
+        self.fields.updated.as_str()
+    }
+
+    /// Returns the subtasks of the issue, these only carry a few fields such
+    /// as the summary, status and issue type
+    pub fn subtasks(&self) -> &[Issue] {
+        self.fields.subtasks.as_slice()
+    }
+
+    /// Returns the issue this is a subtask or child of
+    pub fn parent(&self) -> Option<&Issue> {
+        self.fields.parent.as_deref()
+    }
+
+    /// Returns the fields this library doesn't model keyed by field id, see
+    /// `field` to look one up
+    pub fn extra(&self) -> &BTreeMap<String, ::serde_json::Value> {
+        &self.fields.others
+    }
+
+    /// Returns a field this library doesn't model given its id, such as a
+    /// custom field e.g. "customfield_10010"
+    pub fn field(&self, id: &str) -> Option<&::serde_json::Value> {
+        self.fields.others.get(id)
+    }
+
+    /// Deserializes a field this library doesn't model into `T`, None if the
+    /// issue doesn't have the field or it is null
+    pub fn field_as<T>(&self, id: &str) -> Option<Result<T>>
+    where
+        T: DeserializeOwned,
+    {
+        self.field(id)
+            .filter(|v| !v.is_null())
+            .map(|v| serde_json::from_value(v.clone()).map_err(Error::from))
+    }
+
     /// Fetches a paginated list of issues given an issue key (XXX-123). For more
     /// info consult the api docs:
     /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-getIssue
@@ -330,13 +858,310 @@ impl Issue {
         query.insert("fields".to_string(), field_opts.unwrap_or_default().to_string());
         query.insert("properties".to_string(), property_opts.unwrap_or_default().to_string());
         query.insert("updateHistory".to_string(), update_history.unwrap_or_default().to_string());
-        query.extend(expand_to_hashmap(expand_opts.unwrap_or_default()));
+        query.extend(expand_to_query(expand_opts.unwrap_or_default()));
         c = c.add_query(query);
 
-        let endpoint = format!("api/2/issue/{}", key.into());
+        let endpoint = format!("{}/issue/{}", Api::V2, encode_segment(&key.into()));
 
         c.get(&endpoint)
     }
+
+    /// Creates an issue, see `NewIssue`. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-createIssue
+    pub fn create(c: &Client, issue: NewIssue) -> Response<CreatedIssue> {
+        c.create(&Api::V2.endpoint("issue"), issue)
+    }
+
+    /// Creates an issue as a subtask of the parent given its key or id. The
+    /// parent and the issue type are checked before anything is sent, fails
+    /// with `Error::InvalidSubtask` if the issue type isn't a subtask type,
+    /// the parent is itself a subtask or is in another project. An issue type
+    /// the project of the parent doesn't offer, according to its create meta,
+    /// fails with `Error::InvalidIssueType` listing the subtask types it
+    /// does offer. The project of the parent is used when the issue doesn't
+    /// set one.
+    pub fn create_subtask<P>(c: &Client, parent: P, issue: NewIssue) -> Response<CreatedIssue>
+    where
+        P: Into<String>,
+    {
+        let fields = IssueFieldOptions::new(&["project", "issuetype"]);
+        let parent = Issue::from_key(c, parent, None, Some(fields), None, None, None)?.data;
+
+        if parent.issue_type().map(|t| t.subtask).unwrap_or(false) {
+            return Err(Error::InvalidSubtask(format!(
+                "{} is a subtask and can't have subtasks",
+                parent.key
+            )));
+        }
+
+        let project = parent.project().ok_or(Error::MissingField("project".to_string()))?;
+        let issue = match issue.fields.get("project") {
+            Some(p) if !references(p, &project.id) && !references(p, &project.key) => {
+                return Err(Error::InvalidSubtask(format!(
+                    "the parent {} is in project {}",
+                    parent.key, project.key
+                )))
+            }
+            Some(_) => issue,
+            None => issue.set("project", ::serde_json::json!({ "id": project.id })),
+        };
+
+        let issue_type = issue
+            .fields
+            .get("issuetype")
+            .cloned()
+            .ok_or(Error::MissingField("issuetype".to_string()))?;
+        // Only the issue types the project offers can be created in it
+        let context = ProjectContext::fetch(c, project.key.clone())?;
+
+        match context.issue_type(reference(&issue_type)) {
+            Ok(t) if t.subtask => {}
+            Ok(t) => {
+                return Err(Error::InvalidSubtask(format!(
+                    "issue type {} is not a subtask type",
+                    t.name
+                )))
+            }
+            Err(_) => {
+                return Err(Error::InvalidIssueType {
+                    name: reference(&issue_type).to_string(),
+                    available: context
+                        .issue_types
+                        .iter()
+                        .filter(|t| t.subtask)
+                        .map(|t| t.name.clone())
+                        .collect(),
+                })
+            }
+        }
+
+        let issue = issue.set("parent", ::serde_json::json!({ "key": parent.key }));
+
+        c.create(&Api::V2.endpoint("issue"), issue)
+    }
+
+    /// Deletes an issue, an issue with subtasks can only be deleted if
+    /// `delete_subtasks` is true. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-deleteIssue
+    pub fn delete<I>(c: &Client, key: I, delete_subtasks: bool) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let mut query = Query::new();
+
+        query.insert("deleteSubtasks", delete_subtasks.to_string());
+
+        let endpoint = format!("{}/issue/{}", Api::V2, encode_segment(&key.into()));
+        c.clone().add_query(query).delete(&endpoint)
+    }
+
+    /// Deletes this issue, see `Issue::delete`
+    pub fn delete_self(&self, c: &Client, delete_subtasks: bool) -> Response<()> {
+        Issue::delete(c, self.key.as_str(), delete_subtasks)
+    }
+
+    /// Emails a custom notification about an issue to the recipients it
+    /// describes. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-notify
+    pub fn notify<I>(c: &Client, key: I, notification: &Notification) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/issue/{}/notify", Api::V2, encode_segment(&key.into()));
+        c.post(&endpoint, notification)
+    }
+
+    /// Fetches the transitions available to an issue in its current status
+    /// including the fields each transition accepts. For more info consult
+    /// the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-getTransitions
+    pub fn transitions<I>(c: &Client, key: I) -> Response<Vec<Transition>>
+    where
+        I: Into<String>,
+    {
+        let mut query = Query::new();
+
+        query.insert("expand", "transitions.fields");
+
+        let endpoint = format!("{}/issue/{}/transitions", Api::V2, encode_segment(&key.into()));
+        let Resp { data, headers } = c.clone().add_query(query).get::<Transitions>(&endpoint)?;
+
+        Ok(Resp {
+            data: data.transitions,
+            headers,
+        })
+    }
+
+    /// Performs a transition on an issue, see `IssueTransition`. For more
+    /// info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-doTransition
+    pub fn transition<I>(c: &Client, key: I, transition: IssueTransition) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/issue/{}/transitions", Api::V2, encode_segment(&key.into()));
+        let query = notify_query(transition.notify());
+
+        c.clone().add_query(query).post(&endpoint, transition)
+    }
+
+    /// Performs the transition with the given name on an issue, the name is
+    /// matched ignoring case against the transitions available to the issue.
+    /// Fails with `Error::TransitionNotFound` listing the available names if
+    /// there isn't one.
+    pub fn transition_by_name<I>(
+        c: &Client,
+        key: I,
+        name: &str,
+        fields: BTreeMap<String, ::serde_json::Value>,
+    ) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let key = key.into();
+        let transitions = Issue::transitions(c, key.as_str())?.data;
+        let found = TransitionRef::Name(name.to_string()).find(&transitions)?;
+        let transition = fields
+            .into_iter()
+            .fold(IssueTransition::new(found.id.as_str()), |t, (f, v)| t.set_field(f, v));
+
+        Issue::transition(c, key, transition)
+    }
+
+    /// Performs the same transition on each issue one after the other and
+    /// returns the result for each key, so issues that fail don't hide the
+    /// ones that succeeded. The transition is looked up among those
+    /// available to each issue since ids differ between workflows, an issue
+    /// whose current status doesn't allow it fails with
+    /// `Error::TransitionNotFound`. With `stop_on_error` every key after the
+    /// first failure is still in the results, as `Error::NotAttempted`. A key
+    /// listed more than once is only transitioned the first time.
+    pub fn transition_bulk<K>(
+        c: &Client,
+        keys: &[K],
+        transition: &TransitionRef,
+        fields: &BTreeMap<String, ::serde_json::Value>,
+        options: BulkTransitionOptions,
+    ) -> HashMap<String, Result<()>>
+    where
+        K: AsRef<str>,
+    {
+        let mut results = HashMap::new();
+        let mut stopped = false;
+
+        for key in keys {
+            let key = key.as_ref();
+
+            // A key listed twice is transitioned once
+            if results.contains_key(key) {
+                continue;
+            }
+
+            let result = if stopped {
+                Err(Error::NotAttempted)
+            } else {
+                transition_one(c, key, transition, fields, options.notify_users)
+            };
+
+            stopped |= result.is_err() && options.stop_on_error;
+            results.insert(key.to_string(), result);
+        }
+
+        results
+    }
+
+    /// Fetches the votes on an issue, voters are only included if you have
+    /// permission to view them. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-getVotes
+    pub fn votes<I>(c: &Client, key: I) -> Response<Vote>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/issue/{}/votes", Api::V2, encode_segment(&key.into()));
+        c.get(&endpoint)
+    }
+
+    /// Casts a vote on the issue for the current user. For more info consult
+    /// the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-addVote
+    pub fn vote<I>(c: &Client, key: I) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/issue/{}/votes", Api::V2, encode_segment(&key.into()));
+        c.request(Method::POST, &endpoint, None)
+    }
+
+    /// Removes the current user's vote from the issue. For more info consult
+    /// the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-removeVote
+    pub fn unvote<I>(c: &Client, key: I) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/issue/{}/votes", Api::V2, encode_segment(&key.into()));
+        c.delete(&endpoint)
+    }
+
+    /// Applies a set of field operations to an issue, see `IssueEdit`. This
+    /// is how issues are updated, there is no separate update call, so the
+    /// `notifyUsers` toggle of `IssueEdit::notify_users` lives here. For more
+    /// info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-editIssue
+    pub fn edit<I>(c: &Client, key: I, ops: IssueEdit) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/issue/{}", Api::V2, encode_segment(&key.into()));
+        let query = notify_query(ops.notify_users);
+
+        c.clone().add_query(query).put(&endpoint, ops)
+    }
+
+    /// Assigns an issue given the username of the assignee, None unassigns
+    /// it. The assignment is made as an edit so watchers can be left
+    /// unnotified, see `IssueEdit::notify_users`. For more info consult the
+    /// api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-editIssue
+    pub fn assign<I, U>(c: &Client, key: I, username: Option<U>, notify_users: bool) -> Response<()>
+    where
+        I: Into<String>,
+        U: Into<String>,
+    {
+        let ops = IssueEdit::new().set_assignee(username).notify_users(notify_users);
+        Issue::edit(c, key, ops)
+    }
+
+    /// Applies a set of field operations to an issue only if the precondition
+    /// holds, otherwise JIRA rejects it with `Error::PreconditionFailed`
+    pub fn edit_if<I>(c: &Client, key: I, ops: IssueEdit, p: Precondition) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        Issue::edit(&c.clone().precondition(Some(p)), key, ops)
+    }
+
+    /// Adds labels to an issue leaving any existing labels in place. For more
+    /// info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-editIssue
+    pub fn add_labels<I>(c: &Client, key: I, labels: Vec<String>) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let ops = labels.into_iter().fold(IssueEdit::new(), |e, l| e.add_label(l));
+        Issue::edit(c, key, ops)
+    }
+
+    /// Removes labels from an issue leaving any other labels in place. For
+    /// more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-editIssue
+    pub fn remove_labels<I>(c: &Client, key: I, labels: Vec<String>) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let ops = labels.into_iter().fold(IssueEdit::new(), |e, l| e.remove_label(l));
+        Issue::edit(c, key, ops)
+    }
 }
 
 // ============================================================================
@@ -359,8 +1184,8 @@ impl std::fmt::Display for IssueFields {
 // ============================================================================
 // Private
 // ============================================================================
-fn expand_to_hashmap(e: Vec<IssueExpand>) -> HashMap<String, String> {
-    let mut res: HashMap<String, String> = HashMap::new();
+fn expand_to_query(e: Vec<IssueExpand>) -> Query {
+    let mut res = Query::new();
     let mut value = e.iter().fold(String::from(""), |acc, e| {
         format!("{}{},", acc, e.to_string())
     });
@@ -371,6 +1196,54 @@ fn expand_to_hashmap(e: Vec<IssueExpand>) -> HashMap<String, String> {
     res
 }
 
+/// Returns the id, key or name a reference to another entity such as
+/// `{"key": "EX"}` is made by
+fn reference(value: &::serde_json::Value) -> &str {
+    ["id", "key", "name"]
+        .iter()
+        .find_map(|k| value.get(*k).and_then(|v| v.as_str()))
+        .unwrap_or_default()
+}
+
+/// Returns true if a reference made by id, key or name refers to `other`,
+/// keys and names are matched ignoring case
+fn references(value: &::serde_json::Value, other: &str) -> bool {
+    reference(value).eq_ignore_ascii_case(other)
+}
+
+/// Looks up a transition among those available to an issue and performs it
+fn transition_one(
+    c: &Client,
+    key: &str,
+    transition: &TransitionRef,
+    fields: &BTreeMap<String, ::serde_json::Value>,
+    notify_users: Option<bool>,
+) -> Result<()> {
+    let transitions = Issue::transitions(c, key)?.data;
+    let found = transition.find(&transitions)?;
+    let mut t = fields.iter().fold(IssueTransition::new(found.id.as_str()), |t, (f, v)| {
+        t.set_field(f.as_str(), v.clone())
+    });
+
+    if let Some(notify) = notify_users {
+        t = t.notify_users(notify);
+    }
+
+    Issue::transition(c, key, t).map(|_| ())
+}
+
+/// Returns the `notifyUsers` parameter for a write, nothing is sent unless
+/// it was set so JIRA's default applies
+fn notify_query(notify_users: Option<bool>) -> Query {
+    let mut query = Query::new();
+
+    if let Some(notify) = notify_users {
+        query.insert("notifyUsers", notify.to_string());
+    }
+
+    query
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -385,4 +1258,154 @@ mod tests {
             fs::read_to_string("tests/assets/v2/issue.json").expect("Unable to read in JSON file");
         let _issue: Issue = serde_json::from_str(&results).unwrap();
     }
+
+    #[test]
+    fn test_accessors() {
+        let results =
+            fs::read_to_string("tests/assets/v2/issue.json").expect("Unable to read in JSON file");
+        let issue: Issue = serde_json::from_str(&results).unwrap();
+
+        assert!(issue.summary().starts_with("As an Agile team"));
+        assert_eq!(issue.created(), issue.fields.created);
+        assert_eq!(issue.field_as::<i64>("customfield_10106").unwrap().unwrap(), 2);
+        assert!(issue.field_as::<i64>("customfield_10105").is_none());
+        assert!(issue.field_as::<String>("customfield_10106").unwrap().is_err());
+        assert!(issue.extra().contains_key("customfield_10106"));
+        assert!(!issue.extra().contains_key("summary"));
+        assert_eq!(issue.subtasks().len(), 1);
+        assert_eq!(issue.subtasks()[0].key, "TEST-24");
+        assert!(issue.parent().is_none());
+    }
+
+    #[test]
+    fn test_rendered_fields() {
+        let results =
+            fs::read_to_string("tests/assets/v2/issue.json").expect("Unable to read in JSON file");
+        let mut v: ::serde_json::Value = serde_json::from_str(&results).unwrap();
+
+        v["renderedFields"] = ::serde_json::json!({
+            "description": "<p><b>Steps</b> to reproduce</p>",
+            "comment": {"comments": []},
+            "environment": null
+        });
+
+        let issue: Issue = serde_json::from_value(v).unwrap();
+
+        assert_eq!(issue.rendered("description"), Some("<p><b>Steps</b> to reproduce</p>"));
+        assert_eq!(issue.rendered("environment"), None);
+        assert_eq!(issue.rendered("summary"), None);
+        assert!(!issue.others.contains_key("renderedFields"));
+    }
+
+    #[test]
+    fn test_deserialize_security() {
+        let results =
+            fs::read_to_string("tests/assets/v2/issue.json").expect("Unable to read in JSON file");
+        let mut v: ::serde_json::Value = serde_json::from_str(&results).unwrap();
+
+        // Users who can't see the level get an issue without the field
+        let hidden: Issue = serde_json::from_value(v.clone()).unwrap();
+        assert!(hidden.fields.security.is_none());
+
+        v["fields"]["security"] = ::serde_json::json!({"id": "101", "name": "Staff"});
+
+        let issue: Issue = serde_json::from_value(v).unwrap();
+        assert_eq!(issue.fields.security.unwrap().name, "Staff");
+    }
+
+    #[test]
+    fn test_serialize_edit() {
+        let e = IssueEdit::new()
+            .add_label("urgent")
+            .remove_label("stale")
+            .add_component("Backend")
+            .set_summary("New summary")
+            .set_security("101");
+
+        assert_eq!(
+            serde_json::to_value(&e).unwrap(),
+            ::serde_json::json!({
+                "update": {
+                    "labels": [{"add": "urgent"}, {"remove": "stale"}],
+                    "components": [{"add": {"name": "Backend"}}],
+                    "summary": [{"set": "New summary"}],
+                    "security": [{"set": {"id": "101"}}]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_new_issue() {
+        let issue = NewIssue::builder()
+            .project("EX")
+            .issue_type("10002")
+            .summary("Crash on login")
+            .priority("High")
+            .labels(vec!["auth"])
+            .component("Backend")
+            .component("Web")
+            .custom("customfield_10010", 5)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&issue).unwrap(),
+            ::serde_json::json!({"fields": {
+                "project": {"key": "EX"},
+                "issuetype": {"id": "10002"},
+                "summary": "Crash on login",
+                "priority": {"name": "High"},
+                "labels": ["auth"],
+                "components": [{"name": "Backend"}, {"name": "Web"}],
+                "customfield_10010": 5
+            }})
+        );
+    }
+
+    #[test]
+    fn test_build_subtask() {
+        let epic_link: Field = serde_json::from_value(::serde_json::json!({
+            "id": "customfield_10014",
+            "name": "Epic Link"
+        }))
+        .unwrap();
+        let issue = NewIssue::builder()
+            .project("EX")
+            .issue_type("Sub-task")
+            .summary("Write tests")
+            .parent("EX-1")
+            .epic_link(&epic_link, "EX-100")
+            .build()
+            .unwrap();
+
+        assert_eq!(issue.fields["parent"], ::serde_json::json!({"key": "EX-1"}));
+        assert_eq!(issue.fields["customfield_10014"], "EX-100");
+        assert!(references(&issue.fields["issuetype"], "sub-task"));
+    }
+
+    #[test]
+    fn test_build_new_issue_missing_field() {
+        match NewIssue::builder().project("EX").summary("Crash").build() {
+            Err(Error::MissingField(field)) => assert_eq!(field, "issuetype"),
+            r => panic!("expected MissingField, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_fields() {
+        assert_eq!(Fields::All.to_list(), vec!["*all"]);
+        assert_eq!(
+            Fields::exclude(&["comment", "worklog"]).to_list(),
+            vec!["*all", "-comment", "-worklog"]
+        );
+        assert_eq!(
+            IssueFieldOptions::from(Fields::exclude(&["comment"])).to_string(),
+            "*all,-comment"
+        );
+        assert_eq!(
+            IssueFieldOptions::new(&["*navigable", "-votes"]).to_string(),
+            "*navigable,-votes"
+        );
+    }
 }
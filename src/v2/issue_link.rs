@@ -3,7 +3,9 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
 use crate::v2::{Issue, IssueLinkType};
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
@@ -45,7 +47,7 @@ impl IssueLink {
     where
         I: Into<String>,
     {
-        let url = format!("api/2/issueLink/{}", id.into());
+        let url = format!("{}/issueLink/{}", Api::V2, encode_segment(&id.into()));
         c.get(&url)
     }
 }
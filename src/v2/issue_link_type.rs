@@ -3,6 +3,8 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
@@ -44,7 +46,7 @@ impl IssueLinkType {
     where
         I: Into<String>,
     {
-        let url = format!("api/2/issueLinkType/{}", id.into());
+        let url = format!("{}/issueLinkType/{}", Api::V2, encode_segment(&id.into()));
         c.get(&url)
     }
 }
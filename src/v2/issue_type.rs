@@ -3,6 +3,8 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
@@ -52,9 +54,16 @@ impl IssueType {
     where
         I: Into<String>,
     {
-        let url = format!("api/2/issuetype/{}", id.into());
+        let url = format!("{}/issuetype/{}", Api::V2, encode_segment(&id.into()));
         c.get(&url)
     }
+
+    /// Fetches every issue type on the instance the user can see. For more
+    /// info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issuetype-getIssueAllTypes
+    pub fn all(c: &Client) -> Response<Vec<IssueType>> {
+        c.get(&Api::V2.endpoint("issuetype"))
+    }
 }
 
 // ============================================================================
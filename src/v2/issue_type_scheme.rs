@@ -0,0 +1,138 @@
+//! Issue type schemes decide which issue types can be used in a project,
+//! reading them requires the Administer Jira global permission.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::v2::Pagination;
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IssueTypeScheme {
+    /// ID of the issue type scheme
+    #[serde(default)]
+    pub id: String,
+
+    /// Name of the issue type scheme
+    #[serde(default)]
+    pub name: String,
+
+    /// Description of the issue type scheme
+    #[serde(default)]
+    pub description: String,
+
+    /// ID of the issue type selected by default when creating an issue
+    #[serde(rename = "defaultIssueTypeId", default)]
+    pub default_issue_type_id: Option<String>,
+
+    /// Is this the default issue type scheme
+    #[serde(rename = "isDefault", default)]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedIssueTypeSchemes {
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// Issue type schemes on this page
+    #[serde(default)]
+    pub values: Vec<IssueTypeScheme>,
+}
+
+/// An issue type scheme along with the projects that use it
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IssueTypeSchemeProjects {
+    /// The issue type scheme
+    #[serde(rename = "issueTypeScheme")]
+    pub issue_type_scheme: IssueTypeScheme,
+
+    /// IDs of the projects using the scheme
+    #[serde(rename = "projectIds", default)]
+    pub project_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedIssueTypeSchemeProjects {
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// Schemes and their projects on this page
+    #[serde(default)]
+    pub values: Vec<IssueTypeSchemeProjects>,
+}
+
+impl IssueTypeScheme {
+    /// Fetches a page of the issue type schemes on the instance, a user
+    /// without admin rights gets `Error::Forbidden`. For more info consult
+    /// the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-issue-type-schemes/#api-rest-api-2-issuetypescheme-get
+    pub fn all(c: &Client, page: Option<Pagination>) -> Response<PaginatedIssueTypeSchemes> {
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let query = Client::unpack_options(vec![&page]);
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("issuetypescheme"))
+    }
+
+    /// Fetches a page of the issue type schemes used by the given projects
+    /// along with the projects using each scheme. For more info consult the
+    /// api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-issue-type-schemes/#api-rest-api-2-issuetypescheme-project-get
+    pub fn for_projects<P>(
+        c: &Client,
+        project_ids: &[P],
+        page: Option<Pagination>,
+    ) -> Response<PaginatedIssueTypeSchemeProjects>
+    where
+        P: AsRef<str>,
+    {
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let mut query = Client::unpack_options(vec![&page]);
+
+        for id in project_ids {
+            query.append("projectId", id.as_ref());
+        }
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("issuetypescheme/project"))
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for IssueTypeScheme {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/issue_type_scheme_projects.json")
+            .expect("Unable to read in JSON file");
+        let s: PaginatedIssueTypeSchemeProjects = serde_json::from_str(&results).unwrap();
+
+        assert!(s.pagination.is_last);
+        assert_eq!(s.values.len(), 1);
+        assert_eq!(s.values[0].issue_type_scheme.name, "Default Issue Type Scheme");
+        assert!(s.values[0].issue_type_scheme.is_default);
+        assert_eq!(s.values[0].project_ids, vec!["10000", "10001"]);
+    }
+}
@@ -5,27 +5,66 @@
 // Use
 // ============================================================================
 use crate::{Deserialize, Serialize};
+use serde::{Deserializer, Serializer};
 
 // ============================================================================
 // Public Structures
 // ============================================================================
 #[derive(Debug, Serialize, Deserialize)]
-pub struct Item {
+pub struct Item<T> {
     /// Number of items in the list
     #[serde(default)]
     pub size: i64,
 
     /// Generic list of items
-    #[serde(default)]
-    pub items: ::serde_json::Value,
+    #[serde(default = "Vec::new")]
+    pub items: Vec<T>,
+}
+
+// ============================================================================
+// Crate Functions
+// ============================================================================
+/// Deserializes an `Item` envelope straight into its list of items, for use
+/// with `#[serde(deserialize_with)]`. A missing or null envelope gives an
+/// empty list.
+pub(crate) fn deserialize_items<'de, D, T>(d: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let item: Option<Item<T>> = Option::deserialize(d)?;
+    Ok(item.map(|i| i.items).unwrap_or_default())
+}
+
+/// Serializes a list of items back into an `Item` envelope, for use with
+/// `#[serde(serialize_with)]`
+pub(crate) fn serialize_items<S, T>(items: &[T], s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    #[derive(Serialize)]
+    struct ItemRef<'a, T> {
+        size: usize,
+        items: &'a [T],
+    }
+
+    ItemRef {
+        size: items.len(),
+        items,
+    }
+    .serialize(s)
 }
 
 // ============================================================================
 // Trait Implementations
 // ============================================================================
-impl std::fmt::Display for Item {
+impl<T> std::fmt::Display for Item<T>
+where
+    T: Serialize,
+{
     // This trait requires fmt with this signature
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,140 @@
+//! Metadata about the JQL language on an instance, the fields, functions
+//! and reserved words JIRA accepts, for validating and completing queries.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JqlField {
+    /// The name to use in a query, custom fields may be given as `cf[10000]`
+    #[serde(default)]
+    pub value: String,
+
+    /// Name of the field as shown to users
+    #[serde(rename = "displayName", default)]
+    pub display_name: String,
+
+    /// Can the field be used in an ORDER BY clause
+    #[serde(default)]
+    pub orderable: String,
+
+    /// Can the field be searched
+    #[serde(default)]
+    pub searchable: String,
+
+    /// The id of the custom field in the form `cf[10000]` for custom fields
+    #[serde(default)]
+    pub cfid: Option<String>,
+
+    /// Operators the field supports e.g. "=", "~" or "in"
+    #[serde(default)]
+    pub operators: Vec<String>,
+
+    /// Java types of the values of the field
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JqlFunction {
+    /// The name to use in a query e.g. "currentUser()"
+    #[serde(default)]
+    pub value: String,
+
+    /// Name of the function as shown to users
+    #[serde(rename = "displayName", default)]
+    pub display_name: String,
+
+    /// Does the function return a list of values
+    #[serde(rename = "isList", default)]
+    pub is_list: Option<String>,
+
+    /// Java types of the values the function returns
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JqlAutocompleteData {
+    /// Fields the current user can use in a query
+    #[serde(rename = "visibleFieldNames", default)]
+    pub visible_field_names: Vec<JqlField>,
+
+    /// Functions the current user can use in a query
+    #[serde(rename = "visibleFunctionNames", default)]
+    pub visible_function_names: Vec<JqlFunction>,
+
+    /// Words that need quoting when used as values
+    #[serde(rename = "jqlReservedWords", default)]
+    pub jql_reserved_words: Vec<String>,
+}
+
+impl JqlAutocompleteData {
+    /// Returns the field with the given name, display name or custom field id
+    /// ignoring case, None if the field can't be used in a query
+    pub fn field(&self, name: &str) -> Option<&JqlField> {
+        self.visible_field_names.iter().find(|f| {
+            f.value.eq_ignore_ascii_case(name)
+                || f.display_name.eq_ignore_ascii_case(name)
+                || f.cfid.as_ref().map(|c| c.eq_ignore_ascii_case(name)).unwrap_or(false)
+        })
+    }
+
+    /// Returns true if the word is reserved in JQL and must be quoted
+    pub fn is_reserved(&self, word: &str) -> bool {
+        self.jql_reserved_words.iter().any(|w| w.eq_ignore_ascii_case(word))
+    }
+}
+
+pub struct Jql;
+
+impl Jql {
+    /// Fetches the fields, functions and reserved words available in JQL. For
+    /// more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/jql/autocompletedata-getAutoComplete
+    pub fn autocomplete_data(c: &Client) -> Response<JqlAutocompleteData> {
+        c.get(&Api::V2.endpoint("jql/autocompletedata"))
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for JqlAutocompleteData {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/jql_autocomplete.json")
+            .expect("Unable to read in JSON file");
+        let d: JqlAutocompleteData = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(d.visible_field_names.len(), 2);
+        assert_eq!(d.visible_function_names[0].value, "currentUser()");
+        assert_eq!(d.field("Summary").unwrap().operators, vec!["~", "!~", "is", "is not"]);
+        assert_eq!(d.field("cf[10000]").unwrap().display_name, "Team - cf[10000]");
+        assert!(d.field("nope").is_none());
+        assert!(d.is_reserved("ORDER"));
+        assert!(!d.is_reserved("summary"));
+    }
+}
@@ -0,0 +1,90 @@
+//! Labels in JIRA are free text, this module provides access to the labels
+//! that already exist on an instance for use in autocompletion.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Api;
+use crate::Client;
+use crate::Query;
+use crate::Resp;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LabelSuggestion {
+    /// The label itself
+    #[serde(default)]
+    pub value: String,
+
+    /// The label with the matching part of the query wrapped in <b> tags
+    #[serde(rename = "displayName", default)]
+    pub display_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LabelSuggestions {
+    /// A list of matching labels
+    #[serde(default)]
+    pub results: Vec<LabelSuggestion>,
+}
+
+pub struct Label;
+
+impl Label {
+    /// Fetches existing labels starting with the given query, for more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/jql/autocompletedata-getFieldAutoCompleteForQueryString
+    pub fn suggestions<Q>(c: &Client, query: Q) -> Response<Vec<String>>
+    where
+        Q: Into<String>,
+    {
+        let mut q = Query::new();
+
+        q.insert("fieldName".to_string(), "labels".to_string());
+        q.insert("fieldValue".to_string(), query.into());
+
+        let Resp { data, headers } = c
+            .clone()
+            .add_query(q)
+            .get::<LabelSuggestions>(&Api::V2.endpoint("jql/autocompletedata/suggestions"))?;
+
+        Ok(Resp {
+            data: data.results.into_iter().map(|s| s.value).collect(),
+            headers,
+        })
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for LabelSuggestion {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/label_suggestions.json")
+            .expect("Unable to read in JSON file");
+        let s: LabelSuggestions = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(s.results.len(), 2);
+        assert_eq!(s.results[0].value, "backend");
+        assert_eq!(s.results[0].display_name, "<b>back</b>end");
+    }
+}
@@ -3,25 +3,46 @@
 // ============================================================================
 pub use crate::v2::application_role::*;
 pub use crate::v2::attachment::*;
+pub use crate::v2::avatar::*;
+pub use crate::v2::board::*;
 pub use crate::v2::changelog::*;
 pub use crate::v2::comment::*;
 pub use crate::v2::component::*;
+pub use crate::v2::dashboard::*;
+pub use crate::v2::field::*;
+pub use crate::v2::field_configuration::*;
+pub use crate::v2::filter::*;
 pub use crate::v2::group::*;
 pub use crate::v2::history::*;
 pub use crate::v2::issue::*;
 pub use crate::v2::issue_link::*;
 pub use crate::v2::issue_link_type::*;
 pub use crate::v2::issue_type::*;
+pub use crate::v2::issue_type_scheme::*;
 pub use crate::v2::item::*;
+pub use crate::v2::jql::*;
+pub use crate::v2::label::*;
+pub use crate::v2::notification::*;
 pub use crate::v2::pagination::*;
 pub use crate::v2::permission::*;
+pub use crate::v2::permission_scheme::*;
 pub use crate::v2::priority::*;
 pub use crate::v2::progress::*;
 pub use crate::v2::project::*;
+pub use crate::v2::project_context::*;
+pub use crate::v2::project_role::*;
+pub use crate::v2::properties::*;
+pub use crate::v2::remote_link::*;
 pub use crate::v2::resolution::*;
+pub use crate::v2::screen::*;
+pub use crate::v2::search::*;
+pub use crate::v2::security_level::*;
+pub use crate::v2::server_info::*;
+pub use crate::v2::sprint::*;
 pub use crate::v2::status::*;
 pub use crate::v2::status_category::*;
 pub use crate::v2::time_tracking::*;
+pub use crate::v2::transition::*;
 pub use crate::v2::user::*;
 pub use crate::v2::version::*;
 pub use crate::v2::vote::*;
@@ -45,19 +66,40 @@ mod watches;
 // ============================================================================
 pub mod application_role;
 pub mod attachment;
+pub mod avatar;
+pub mod board;
 pub mod component;
+pub mod dashboard;
+pub mod field;
+pub mod field_configuration;
+pub mod filter;
 pub mod group;
 pub mod issue;
 pub mod issue_link;
 pub mod issue_link_type;
 pub mod issue_type;
+pub mod issue_type_scheme;
+pub mod jql;
+pub mod label;
+pub mod notification;
 pub mod pagination;
 pub mod permission;
+pub mod permission_scheme;
 pub mod priority;
 pub mod project;
+pub mod project_context;
+pub mod project_role;
+pub mod properties;
+pub mod remote_link;
 pub mod resolution;
+pub mod screen;
+pub mod search;
+pub mod security_level;
+pub mod server_info;
+pub mod sprint;
 pub mod status;
 pub mod status_category;
+pub mod transition;
 pub mod user;
 pub mod version;
 pub mod worklog;
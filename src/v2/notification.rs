@@ -0,0 +1,160 @@
+//! Custom email notifications about an issue, sent through
+//! `Issue::notify`.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Serialize;
+use serde::ser::{SerializeSeq, Serializer};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+/// Who should receive a notification, the flags select people by their role
+/// on the issue.
+#[derive(Debug, Default, Serialize)]
+pub struct NotificationRecipients {
+    /// Notify the reporter of the issue
+    pub reporter: bool,
+
+    /// Notify the assignee of the issue
+    pub assignee: bool,
+
+    /// Notify everyone watching the issue
+    pub watchers: bool,
+
+    /// Notify everyone that voted on the issue
+    pub voters: bool,
+
+    /// Usernames of additional users to notify
+    #[serde(serialize_with = "named", skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<String>,
+
+    /// Names of groups whose members should be notified
+    #[serde(serialize_with = "named", skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
+}
+
+/// Limits the recipients to those in the groups or holding the permissions
+/// given, useful to avoid leaking restricted issues.
+#[derive(Debug, Default, Serialize)]
+pub struct NotificationRestriction {
+    /// Names of groups a recipient must belong to
+    #[serde(serialize_with = "named", skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
+
+    /// Permission keys a recipient must hold e.g. "BROWSE"
+    #[serde(serialize_with = "keyed", skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Notification {
+    /// Subject of the email, defaults to the issue key and summary when empty
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub subject: String,
+
+    /// Plain text body of the email
+    #[serde(rename = "textBody", skip_serializing_if = "String::is_empty")]
+    pub text_body: String,
+
+    /// HTML body of the email
+    #[serde(rename = "htmlBody", skip_serializing_if = "String::is_empty")]
+    pub html_body: String,
+
+    /// Who receives the email
+    pub to: NotificationRecipients,
+
+    /// Restricts who can receive the email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrict: Option<NotificationRestriction>,
+}
+
+impl Notification {
+    /// Returns a notification with the given subject and plain text body
+    /// that has no recipients yet
+    pub fn new<S, T>(subject: S, text_body: T) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Notification {
+            subject: subject.into(),
+            text_body: text_body.into(),
+            ..Default::default()
+        }
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+/// JIRA expects users and groups as a list of `{"name": ...}` objects
+fn named<S>(names: &[String], s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct Named<'a> {
+        name: &'a str,
+    }
+
+    let mut seq = s.serialize_seq(Some(names.len()))?;
+    for name in names {
+        seq.serialize_element(&Named { name })?;
+    }
+    seq.end()
+}
+
+/// JIRA expects permissions as a list of `{"key": ...}` objects
+fn keyed<S>(keys: &[String], s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct Keyed<'a> {
+        key: &'a str,
+    }
+
+    let mut seq = s.serialize_seq(Some(keys.len()))?;
+    for key in keys {
+        seq.serialize_element(&Keyed { key })?;
+    }
+    seq.end()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_notification() {
+        let mut n = Notification::new("Release", "TEST-1 ships today");
+        n.to.watchers = true;
+        n.to.users = vec!["fred".to_string()];
+        n.restrict = Some(NotificationRestriction {
+            permissions: vec!["BROWSE".to_string()],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            serde_json::to_value(&n).unwrap(),
+            json!({
+                "subject": "Release",
+                "textBody": "TEST-1 ships today",
+                "to": {
+                    "reporter": false,
+                    "assignee": false,
+                    "watchers": true,
+                    "voters": false,
+                    "users": [{"name": "fred"}]
+                },
+                "restrict": {"permissions": [{"key": "BROWSE"}]}
+            })
+        );
+    }
+}
@@ -4,14 +4,16 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::Error;
 use crate::Options;
+use crate::Query;
+use crate::Result;
 use crate::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 // ============================================================================
 // Public Structures
 // ============================================================================
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Pagination {
     /// REST API link to group
     #[serde(rename = "startAt", default)]
@@ -30,11 +32,25 @@ pub struct Pagination {
     pub total: i64,
 
     /// Is the last page
-    #[serde(default)]
+    #[serde(rename = "isLast", default)]
     pub is_last: bool,
 }
 
 impl Pagination {
+    /// Largest page JIRA Server returns for issue searches, Cloud caps
+    /// searches at 100 and reports the lower value in the response
+    pub const MAX_SEARCH: i64 = 1000;
+
+    /// Largest page returned when searching for users
+    pub const MAX_USERS: i64 = 1000;
+
+    /// Largest page of dashboards returned
+    pub const MAX_DASHBOARDS: i64 = 1000;
+
+    /// Largest page returned by most other endpoints, including group
+    /// members, the agile API and the admin schemes
+    pub const MAX_DEFAULT: i64 = 50;
+
     pub fn new(start: i64, max: i64) -> Pagination {
         Pagination {
             start_at: start,
@@ -60,6 +76,80 @@ impl Pagination {
     }
 }
 
+impl Pagination {
+    /// Validates the page requested from an endpoint, a start below zero or
+    /// a page size of zero or less is rejected with
+    /// `Error::InvalidPagination`. A page size above `limit`, the most the
+    /// endpoint returns, is lowered to it; JIRA may still return fewer so
+    /// compare with the `max_results` of the response, see `is_clamped`.
+    pub fn checked(page: Option<Pagination>, limit: i64) -> Result<Pagination> {
+        let page = page.unwrap_or_default();
+
+        if page.start_at < 0 {
+            return Err(Error::InvalidPagination(format!(
+                "start_at must not be negative, got {}",
+                page.start_at
+            )));
+        }
+
+        if page.max_results <= 0 {
+            return Err(Error::InvalidPagination(format!(
+                "max_results must be greater than zero, got {}",
+                page.max_results
+            )));
+        }
+
+        Ok(Pagination {
+            max_results: page.max_results.min(limit),
+            ..page
+        })
+    }
+
+    /// Returns true if the page size in a response is smaller than the
+    /// `requested` size, meaning JIRA capped the page
+    pub fn is_clamped(&self, requested: i64) -> bool {
+        self.max_results < requested
+    }
+
+    /// Fetches every page from a paginated endpoint and collects the values
+    /// into a single vector. `fetch` is given the page to request and returns
+    /// the pagination fields of the response along with its values. Fetching
+    /// stops once a page is empty, marked as the last page or the total is
+    /// reached; if `cap` is given at most that many values are returned. Any
+    /// error aborts the whole collection rather than returning a partial
+    /// result.
+    pub fn collect_all<T, F>(max_results: i64, cap: Option<usize>, mut fetch: F) -> Result<Vec<T>>
+    where
+        F: FnMut(Pagination) -> Result<(Pagination, Vec<T>)>,
+    {
+        let mut all = Vec::new();
+        let mut page = Pagination::new(0, max_results);
+
+        loop {
+            let (p, values) = fetch(page)?;
+            let fetched = values.len() as i64;
+
+            all.extend(values);
+
+            if let Some(cap) = cap {
+                if all.len() >= cap {
+                    all.truncate(cap);
+                    break;
+                }
+            }
+
+            let start = p.start_at + fetched;
+            if fetched == 0 || p.is_last || (p.total > 0 && start >= p.total) {
+                break;
+            }
+
+            page = Pagination::new(start, max_results);
+        }
+
+        Ok(all)
+    }
+}
+
 impl Default for Pagination {
     fn default() -> Self {
         Pagination {
@@ -73,8 +163,8 @@ impl Default for Pagination {
 }
 
 impl Options for Pagination {
-    fn to_query(&self) -> HashMap<String, String> {
-        let mut h = HashMap::new();
+    fn to_query(&self) -> Query {
+        let mut h = Query::new();
         h.insert(String::from("startAt"), self.start_at.to_string());
         h.insert(String::from("maxResults"), self.max_results.to_string());
         h
@@ -97,6 +187,69 @@ mod tests {
         assert_eq!(p.start_at, 3);
     }
 
+    #[test]
+    fn test_collect_all() {
+        let mut starts = Vec::new();
+        let all = Pagination::collect_all(2, None, |p| {
+            starts.push(p.start_at);
+            let values: Vec<i64> = (p.start_at..(p.start_at + p.max_results).min(5)).collect();
+            Ok((
+                Pagination {
+                    total: 5,
+                    ..p
+                },
+                values,
+            ))
+        })
+        .unwrap();
+
+        assert_eq!(all, vec![0, 1, 2, 3, 4]);
+        assert_eq!(starts, vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn test_collect_all_cap() {
+        let all = Pagination::collect_all(2, Some(3), |p| {
+            Ok((Pagination::new(p.start_at, 2), vec![p.start_at, p.start_at + 1]))
+        })
+        .unwrap();
+
+        assert_eq!(all, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_collect_all_error() {
+        let all: Result<Vec<i64>> = Pagination::collect_all(2, None, |p| {
+            if p.start_at == 0 {
+                Ok((Pagination::new(0, 2), vec![0, 1]))
+            } else {
+                Err(crate::Error::NotFound)
+            }
+        });
+
+        assert!(all.is_err());
+    }
+
+    #[test]
+    fn test_checked_clamps() {
+        let p = Pagination::checked(Some(Pagination::new(10, 5000)), Pagination::MAX_SEARCH)
+            .unwrap();
+
+        assert_eq!(p.start_at, 10);
+        assert_eq!(p.max_results, 1000);
+        assert_eq!(Pagination::checked(None, 20).unwrap().max_results, 20);
+        assert!(Pagination::new(0, 100).is_clamped(1000));
+    }
+
+    #[test]
+    fn test_checked_rejects_invalid() {
+        let zero = Pagination::checked(Some(Pagination::new(0, 0)), Pagination::MAX_DEFAULT);
+        let negative = Pagination::checked(Some(Pagination::new(-1, 10)), Pagination::MAX_DEFAULT);
+
+        assert!(matches!(zero, Err(Error::InvalidPagination(_))));
+        assert!(matches!(negative, Err(Error::InvalidPagination(_))));
+    }
+
     #[test]
     fn test_no_more_pages() {
         let mut p = Pagination::default();
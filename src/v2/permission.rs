@@ -3,9 +3,11 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
+use crate::Query;
 use crate::QueryOptions;
 use std::collections::BTreeMap;
 
@@ -81,7 +83,7 @@ pub struct MyPermission {
     pub have_permission: bool,
 
     /// Is the permission key deprecated?
-    #[serde(default)]
+    #[serde(rename = "deprecatedKey", default)]
     pub deprecated_key: bool,
 }
 
@@ -92,6 +94,34 @@ pub struct MyPermissionCollection {
 }
 
 impl MyPermission {
+    /// Fetches the permissions the currently authenticated user has, scoped
+    /// to a project and/or issue when given. Newer versions of JIRA Cloud
+    /// require the permission keys being checked (e.g. `EDIT_ISSUES`) to be
+    /// listed explicitly, see the docs for more details:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2-getPermissions
+    pub fn my(
+        c: &Client,
+        project_key: Option<&str>,
+        issue_key: Option<&str>,
+        permissions: Option<&[&str]>,
+    ) -> Response<MyPermissionCollection> {
+        let mut query = Query::new();
+
+        if let Some(p) = project_key {
+            query.insert(MyPermissionKey::ProjectKey.to_string(), p.to_string());
+        }
+
+        if let Some(i) = issue_key {
+            query.insert(MyPermissionKey::IssueKey.to_string(), i.to_string());
+        }
+
+        if let Some(p) = permissions {
+            query.insert("permissions".to_string(), p.join(","));
+        }
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("mypermissions"))
+    }
+
     /// Fetches permissions for the given permission key using the currently 
     /// authenticated credentials, see the docs for more details:
     /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2-getPermissions
@@ -103,9 +133,11 @@ impl MyPermission {
     where
         P: Into<String>,
     {
-        let url = format!("/api/2/mypermissions?{}={}", key.to_string(), value.into());
+        let mut query = Query::new();
 
-        c.get(&url)
+        query.insert(key.to_string(), value);
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("mypermissions"))
     }
 }
 
@@ -117,7 +149,7 @@ impl Permission {
         c: &Client
     ) -> Response<PermissionCollection>
     {
-        let url = "/api/2/permissions";
+        let url = Api::V2.endpoint("permissions");
 
         c.get(&url)
     }
@@ -0,0 +1,280 @@
+//! Permission schemes grant project permissions to users, groups and roles,
+//! every project uses exactly one. Reading them requires the Administer Jira
+//! global permission or Administer Projects on the project.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
+use crate::Client;
+use crate::Query;
+use crate::QueryOptions;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Enums
+// ============================================================================
+pub enum PermissionSchemeExpand {
+    Permissions,
+    User,
+    Group,
+    ProjectRole,
+    Field,
+    All,
+}
+
+impl QueryOptions for PermissionSchemeExpand {
+    fn to_string(&self) -> String {
+        match &self {
+            PermissionSchemeExpand::Permissions => "permissions".to_string(),
+            PermissionSchemeExpand::User => "user".to_string(),
+            PermissionSchemeExpand::Group => "group".to_string(),
+            PermissionSchemeExpand::ProjectRole => "projectRole".to_string(),
+            PermissionSchemeExpand::Field => "field".to_string(),
+            PermissionSchemeExpand::All => "all".to_string(),
+        }
+    }
+}
+
+/// Who a permission is granted to. Holder types this library doesn't know
+/// about, such as custom field holders, are kept in `Other`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawHolder", into = "RawHolder")]
+pub enum PermissionHolder {
+    /// A user given their username, or account id on JIRA Cloud
+    User(String),
+
+    /// A group given its name
+    Group(String),
+
+    /// Members of a project role given the id of the role
+    ProjectRole(String),
+
+    /// The lead of the project
+    ProjectLead,
+
+    /// The reporter of the issue
+    Reporter,
+
+    /// The assignee of the issue
+    Assignee,
+
+    /// Users of an application given its key, any logged in user if None
+    ApplicationRole(Option<String>),
+
+    /// Anyone including anonymous users
+    Anyone,
+
+    /// Any other holder type along with its parameter
+    Other {
+        holder_type: String,
+        parameter: Option<String>,
+    },
+}
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PermissionScheme {
+    /// REST API link to the permission scheme
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// ID of the permission scheme
+    #[serde(default)]
+    pub id: i64,
+
+    /// Name of the permission scheme
+    #[serde(default)]
+    pub name: String,
+
+    /// Description of the permission scheme
+    #[serde(default)]
+    pub description: String,
+
+    /// Permissions granted by the scheme, only returned when fetched with
+    /// `PermissionSchemeExpand::Permissions`
+    #[serde(default)]
+    pub permissions: Vec<PermissionGrant>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PermissionGrant {
+    /// ID of the grant
+    #[serde(default)]
+    pub id: i64,
+
+    /// Who the permission is granted to
+    pub holder: PermissionHolder,
+
+    /// Key of the permission e.g. "BROWSE_PROJECTS", see `Permission::all`
+    #[serde(default)]
+    pub permission: String,
+}
+
+impl PermissionScheme {
+    /// Fetches the permission scheme of a project given its key or id along
+    /// with the permissions it grants. A user without admin rights gets
+    /// `Error::Forbidden`. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project/{projectKeyOrId}/permissionscheme-getAssignedPermissionScheme
+    pub fn for_project<K>(c: &Client, project_key: K) -> Response<PermissionScheme>
+    where
+        K: Into<String>,
+    {
+        let url = format!(
+            "{}/project/{}/permissionscheme",
+            Api::V2,
+            encode_segment(&project_key.into())
+        );
+        let query = expand_to_query(vec![PermissionSchemeExpand::Permissions]);
+
+        c.clone().add_query(query).get(&url)
+    }
+
+    /// Fetches a permission scheme given its id, the permissions it grants
+    /// are only included when expanded. A user without admin rights gets
+    /// `Error::Forbidden`. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/permissionscheme-getPermissionScheme
+    pub fn get(
+        c: &Client,
+        id: i64,
+        expand: Option<Vec<PermissionSchemeExpand>>,
+    ) -> Response<PermissionScheme> {
+        let url = format!("{}/permissionscheme/{}", Api::V2, id);
+        let query = expand_to_query(expand.unwrap_or_default());
+
+        c.clone().add_query(query).get(&url)
+    }
+
+    /// Returns the grants of a permission e.g. "BROWSE_PROJECTS"
+    pub fn grants<'a>(
+        &'a self,
+        permission: &'a str,
+    ) -> impl Iterator<Item = &'a PermissionGrant> {
+        self.permissions.iter().filter(move |g| g.permission == permission)
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for PermissionScheme {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+impl From<RawHolder> for PermissionHolder {
+    fn from(raw: RawHolder) -> Self {
+        match (raw.holder_type.as_str(), raw.parameter) {
+            ("user", Some(p)) => PermissionHolder::User(p),
+            ("group", Some(p)) => PermissionHolder::Group(p),
+            ("projectRole", Some(p)) => PermissionHolder::ProjectRole(p),
+            ("projectLead", None) => PermissionHolder::ProjectLead,
+            ("reporter", None) => PermissionHolder::Reporter,
+            ("assignee", None) => PermissionHolder::Assignee,
+            ("applicationRole", p) => PermissionHolder::ApplicationRole(p),
+            ("anyone", None) => PermissionHolder::Anyone,
+            (_, parameter) => PermissionHolder::Other {
+                holder_type: raw.holder_type,
+                parameter,
+            },
+        }
+    }
+}
+
+impl From<PermissionHolder> for RawHolder {
+    fn from(holder: PermissionHolder) -> Self {
+        let (holder_type, parameter) = match holder {
+            PermissionHolder::User(p) => ("user".to_string(), Some(p)),
+            PermissionHolder::Group(p) => ("group".to_string(), Some(p)),
+            PermissionHolder::ProjectRole(p) => ("projectRole".to_string(), Some(p)),
+            PermissionHolder::ProjectLead => ("projectLead".to_string(), None),
+            PermissionHolder::Reporter => ("reporter".to_string(), None),
+            PermissionHolder::Assignee => ("assignee".to_string(), None),
+            PermissionHolder::ApplicationRole(p) => ("applicationRole".to_string(), p),
+            PermissionHolder::Anyone => ("anyone".to_string(), None),
+            PermissionHolder::Other {
+                holder_type,
+                parameter,
+            } => (holder_type, parameter),
+        };
+
+        RawHolder {
+            holder_type,
+            parameter,
+        }
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+/// A holder as JIRA sends it, `parameter` names the user, group or role
+#[derive(Serialize, Deserialize)]
+struct RawHolder {
+    #[serde(rename = "type", default)]
+    holder_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    parameter: Option<String>,
+}
+
+fn expand_to_query(e: Vec<PermissionSchemeExpand>) -> Query {
+    let mut res = Query::new();
+    let value: Vec<String> = e.iter().map(|e| e.to_string()).collect();
+
+    if !value.is_empty() {
+        res.insert("expand", value.join(","));
+    }
+
+    res
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/permission_scheme.json")
+            .expect("Unable to read in JSON file");
+        let s: PermissionScheme = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(s.id, 10000);
+        assert_eq!(s.name, "Default Permission Scheme");
+        assert_eq!(s.permissions.len(), 5);
+        assert_eq!(s.permissions[0].holder, PermissionHolder::Group("jira-developers".into()));
+        assert_eq!(s.permissions[1].holder, PermissionHolder::ProjectRole("10002".into()));
+        assert_eq!(s.permissions[2].holder, PermissionHolder::ProjectLead);
+        assert_eq!(s.permissions[3].holder, PermissionHolder::User("fred".into()));
+        assert_eq!(
+            s.permissions[4].holder,
+            PermissionHolder::Other {
+                holder_type: "userCustomField".into(),
+                parameter: Some("customfield_10100".into()),
+            }
+        );
+        assert_eq!(s.grants("BROWSE_PROJECTS").count(), 2);
+    }
+
+    #[test]
+    fn test_serialize_holder() {
+        assert_eq!(
+            serde_json::to_value(PermissionHolder::ProjectLead).unwrap(),
+            ::serde_json::json!({"type": "projectLead"})
+        );
+        assert_eq!(
+            serde_json::to_value(PermissionHolder::Group("admins".into())).unwrap(),
+            ::serde_json::json!({"type": "group", "parameter": "admins"})
+        );
+    }
+}
@@ -3,6 +3,8 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
@@ -47,7 +49,7 @@ impl Priority {
     where
         I: Into<String>,
     {
-        let url = format!("api/2/priority/{}", id.into());
+        let url = format!("{}/priority/{}", Api::V2, encode_segment(&id.into()));
         c.get(&url)
     }
 }
@@ -3,13 +3,19 @@
 // ============================================================================
 // Use
 // ============================================================================
-use crate::v2::{Component, IssueType, User, Version};
+use crate::client::encode_segment;
+use crate::v2::avatar;
+use crate::v2::{AvatarCrop, Component, IssueType, ProjectContext, ServerInfo, User, Version};
+use crate::Api;
 use crate::Client;
+use crate::Error;
+use crate::Method;
+use crate::Query;
 use crate::QueryOptions;
 use crate::Response;
+use crate::Result;
 use crate::{Deserialize, Serialize};
 use std::collections::BTreeMap;
-use std::collections::HashMap;
 
 // ============================================================================
 // Public Enums
@@ -32,6 +38,16 @@ impl QueryOptions for ProjectExpand {
     }
 }
 
+/// Who new issues in a project are assigned to when no assignee is given
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AssigneeType {
+    #[serde(rename = "PROJECT_LEAD")]
+    ProjectLead,
+
+    #[serde(rename = "UNASSIGNED")]
+    Unassigned,
+}
+
 // ============================================================================
 // Public Structures
 // ============================================================================
@@ -94,6 +110,252 @@ pub struct Project {
     pub archived: bool
 }
 
+/// A project to create, build one with `NewProject::builder`
+#[derive(Debug, Default, Serialize)]
+pub struct NewProject {
+    key: String,
+
+    name: String,
+
+    #[serde(rename = "projectTypeKey")]
+    project_type_key: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lead: Option<String>,
+
+    #[serde(rename = "leadAccountId", skip_serializing_if = "Option::is_none")]
+    lead_account_id: Option<String>,
+
+    #[serde(rename = "assigneeType", skip_serializing_if = "Option::is_none")]
+    assignee_type: Option<AssigneeType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+
+    #[serde(rename = "projectTemplateKey", skip_serializing_if = "Option::is_none")]
+    project_template_key: Option<String>,
+}
+
+impl NewProject {
+    /// Returns a builder for a project
+    pub fn builder() -> NewProjectBuilder {
+        NewProjectBuilder::default()
+    }
+}
+
+/// Builds a `NewProject`, `build` fails with `Error::MissingField` unless the
+/// key, name, project type, lead and assignee type are set. The lead is
+/// given by username on Server and by account id on Cloud.
+///
+/// Examples:
+/// NewProject::builder().key("EX").name("Example").project_type_key("software")
+///     .lead("fred").assignee_type(AssigneeType::ProjectLead).build()
+#[derive(Debug, Default)]
+pub struct NewProjectBuilder {
+    project: NewProject,
+}
+
+impl NewProjectBuilder {
+    /// Sets the key, e.g. "EX" for issues EX-1, EX-2 and so on
+    pub fn key<K>(mut self, key: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.project.key = key.into();
+        self
+    }
+
+    /// Sets the name
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.project.name = name.into();
+        self
+    }
+
+    /// Sets the type of the project, e.g. "software" or "business"
+    pub fn project_type_key<T>(mut self, key: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.project.project_type_key = key.into();
+        self
+    }
+
+    /// Sets the lead given their username, for JIRA Server
+    pub fn lead<U>(mut self, username: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.project.lead = Some(username.into());
+        self
+    }
+
+    /// Sets the lead given their account id, for JIRA Cloud
+    pub fn lead_account_id<A>(mut self, account_id: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.project.lead_account_id = Some(account_id.into());
+        self
+    }
+
+    /// Sets who new issues are assigned to by default
+    pub fn assignee_type(mut self, assignee_type: AssigneeType) -> Self {
+        self.project.assignee_type = Some(assignee_type);
+        self
+    }
+
+    /// Sets the description
+    pub fn description<D>(mut self, description: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.project.description = Some(description.into());
+        self
+    }
+
+    /// Sets the URL shown on the project
+    pub fn url<U>(mut self, url: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.project.url = Some(url.into());
+        self
+    }
+
+    /// Sets the template the project is created from, e.g.
+    /// "com.pyxis.greenhopper.jira:gh-simplified-kanban-classic"
+    pub fn project_template_key<T>(mut self, key: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.project.project_template_key = Some(key.into());
+        self
+    }
+
+    /// Returns the project, fails with `Error::MissingField` naming the first
+    /// required field that wasn't set
+    pub fn build(self) -> Result<NewProject> {
+        let p = &self.project;
+        let missing = [
+            ("key", p.key.is_empty()),
+            ("name", p.name.is_empty()),
+            ("projectTypeKey", p.project_type_key.is_empty()),
+            ("lead", p.lead.is_none() && p.lead_account_id.is_none()),
+            ("assigneeType", p.assignee_type.is_none()),
+        ];
+
+        match missing.iter().find(|(_, missing)| *missing) {
+            Some((field, _)) => Err(Error::MissingField(field.to_string())),
+            None => Ok(self.project),
+        }
+    }
+}
+
+/// Changes to make to a project, fields left as None are not changed
+///
+/// Examples:
+/// ProjectUpdate::new().name("Renamed").assignee_type(AssigneeType::Unassigned)
+#[derive(Debug, Default, Serialize)]
+pub struct ProjectUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lead: Option<String>,
+
+    #[serde(rename = "leadAccountId", skip_serializing_if = "Option::is_none")]
+    lead_account_id: Option<String>,
+
+    #[serde(rename = "assigneeType", skip_serializing_if = "Option::is_none")]
+    assignee_type: Option<AssigneeType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+impl ProjectUpdate {
+    /// Returns an update that changes nothing
+    pub fn new() -> Self {
+        ProjectUpdate::default()
+    }
+
+    /// Renames the project
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Changes the description
+    pub fn description<D>(mut self, description: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Changes the lead given their username, for JIRA Server
+    pub fn lead<U>(mut self, username: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.lead = Some(username.into());
+        self
+    }
+
+    /// Changes the lead given their account id, for JIRA Cloud
+    pub fn lead_account_id<A>(mut self, account_id: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.lead_account_id = Some(account_id.into());
+        self
+    }
+
+    /// Changes who new issues are assigned to by default
+    pub fn assignee_type(mut self, assignee_type: AssigneeType) -> Self {
+        self.assignee_type = Some(assignee_type);
+        self
+    }
+
+    /// Changes the URL shown on the project
+    pub fn url<U>(mut self, url: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.url = Some(url.into());
+        self
+    }
+}
+
+/// The reference to a project JIRA returns once it has been created
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatedProject {
+    /// REST API link to the project
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// ID of the project
+    #[serde(default)]
+    pub id: i64,
+
+    /// Key of the project
+    #[serde(default)]
+    pub key: String,
+}
+
 impl Project {
     /// Fetches a project from key or id, for more information see:
     /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project-getProject
@@ -105,14 +367,123 @@ impl Project {
     where
         K: Into<String>,
     {
-        let url = format!("api/2/project/{}", key.into());
+        let url = format!("{}/project/{}", Api::V2, encode_segment(&key.into()));
         let mut c = c.clone();
-        let query = expand_to_hashmap(expand_opts.unwrap_or_default());
+        let query = expand_to_query(expand_opts.unwrap_or_default());
 
         c = c.add_query(query);
  
         c.get(&url)
     }
+
+    /// Fetches every component of a project. For more info consult the api
+    /// docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project-getProjectComponents
+    pub fn components<K>(c: &Client, key: K) -> Response<Vec<Component>>
+    where
+        K: Into<String>,
+    {
+        let endpoint = format!("{}/project/{}/components", Api::V2, encode_segment(&key.into()));
+        c.get(&endpoint)
+    }
+
+    /// Fetches every version of a project. For more info consult the api
+    /// docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project-getProjectVersions
+    pub fn versions<K>(c: &Client, key: K) -> Response<Vec<Version>>
+    where
+        K: Into<String>,
+    {
+        let endpoint = format!("{}/project/{}/versions", Api::V2, encode_segment(&key.into()));
+        c.get(&endpoint)
+    }
+
+    /// Creates a project, requires the Administer Jira global permission. For
+    /// more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project-createProject
+    pub fn create(c: &Client, project: NewProject) -> Response<CreatedProject> {
+        c.create(&Api::V2.endpoint("project"), project)
+    }
+
+    /// Updates a project given its key or id, only the fields set on the
+    /// update are changed. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project-updateProject
+    pub fn update<K>(c: &Client, key: K, update: ProjectUpdate) -> Response<Project>
+    where
+        K: Into<String>,
+    {
+        let endpoint = format!("{}/project/{}", Api::V2, encode_segment(&key.into()));
+        c.put(&endpoint, update)
+    }
+
+    /// Deletes a project given its key or id along with all of its issues,
+    /// this can't be undone. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project-deleteProject
+    pub fn delete<K>(c: &Client, key: K) -> Response<()>
+    where
+        K: Into<String>,
+    {
+        let endpoint = format!("{}/project/{}", Api::V2, encode_segment(&key.into()));
+        c.delete(&endpoint)
+    }
+
+    /// Archives a project given its key or id, its issues become read only.
+    /// Only JIRA Cloud can archive projects through the API, on Server this
+    /// fails with `Error::NotSupported` without sending the request. For more
+    /// info consult the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-projects/#api-rest-api-2-project-projectidorkey-archive-post
+    pub fn archive<K>(c: &Client, key: K) -> Response<()>
+    where
+        K: Into<String>,
+    {
+        cloud_only(c, "archiving projects")?;
+
+        let endpoint = format!("{}/project/{}/archive", Api::V2, encode_segment(&key.into()));
+        c.request(Method::POST, &endpoint, None)
+    }
+
+    /// Restores an archived or deleted project given its key or id, like
+    /// `archive` this is only available on JIRA Cloud. For more info consult
+    /// the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-projects/#api-rest-api-2-project-projectidorkey-restore-post
+    pub fn restore<K>(c: &Client, key: K) -> Response<Project>
+    where
+        K: Into<String>,
+    {
+        cloud_only(c, "restoring projects")?;
+
+        let endpoint = format!("{}/project/{}/restore", Api::V2, encode_segment(&key.into()));
+        c.request(Method::POST, &endpoint, None)
+    }
+
+    /// Returns a context for creating issues in a project given its key, the
+    /// issue types the project accepts are fetched once up front. See
+    /// `ProjectContext`.
+    pub fn context<K>(c: &Client, key: K) -> Result<ProjectContext>
+    where
+        K: Into<String>,
+    {
+        ProjectContext::fetch(c, key.into())
+    }
+
+    /// Uploads an image and makes it the avatar of the project returning the
+    /// id of the new avatar. Without a crop the largest square JIRA can fit
+    /// in the image is used. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project-storeTemporaryAvatar
+    pub fn set_avatar<K>(
+        c: &Client,
+        key: K,
+        image: Vec<u8>,
+        content_type: &str,
+        crop: Option<AvatarCrop>,
+    ) -> Response<String>
+    where
+        K: Into<String>,
+    {
+        let endpoint = format!("{}/project/{}/avatar", Api::V2, encode_segment(&key.into()));
+
+        avatar::upload(c, &endpoint, Query::new(), image, content_type, crop)
+    }
 }
 
 // ============================================================================
@@ -128,8 +499,17 @@ impl std::fmt::Display for Project {
 // ============================================================================
 // Private
 // ============================================================================
-fn expand_to_hashmap(e: Vec<ProjectExpand>) -> HashMap<String, String> {
-    let mut res: HashMap<String, String> = HashMap::new();
+/// Fails with `Error::NotSupported` unless the instance is JIRA Cloud
+fn cloud_only(c: &Client, feature: &str) -> Result<()> {
+    if ServerInfo::get(c)?.data.is_cloud() {
+        Ok(())
+    } else {
+        Err(Error::NotSupported(format!("{} requires JIRA Cloud", feature)))
+    }
+}
+
+fn expand_to_query(e: Vec<ProjectExpand>) -> Query {
+    let mut res = Query::new();
     let mut value = e.iter().fold(String::from(""), |acc, e| {
         format!("{}{},", acc, e.to_string())
     });
@@ -170,4 +550,32 @@ mod tests {
         assert_eq!(project.name, "TEST");
         assert_eq!(project.roles.len(), 2);
     }
+
+    #[test]
+    fn test_build_new_project() {
+        let project = NewProject::builder()
+            .key("EX")
+            .name("Example")
+            .project_type_key("software")
+            .lead("fred")
+            .assignee_type(AssigneeType::ProjectLead)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&project).unwrap(),
+            ::serde_json::json!({
+                "key": "EX",
+                "name": "Example",
+                "projectTypeKey": "software",
+                "lead": "fred",
+                "assigneeType": "PROJECT_LEAD"
+            })
+        );
+
+        match NewProject::builder().key("EX").name("Example").project_type_key("software").build() {
+            Err(Error::MissingField(field)) => assert_eq!(field, "lead"),
+            r => panic!("expected MissingField, got {:?}", r),
+        }
+    }
 }
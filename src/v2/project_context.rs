@@ -0,0 +1,262 @@
+//! A project context remembers the details of a project needed to create
+//! issues in it, so creating many issues doesn't repeat the project and
+//! reporter or send issue types the project doesn't accept. Components and
+//! versions are fetched the first time they are looked up and kept for the
+//! life of the context.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::v2::{Component, CreatedIssue, Issue, IssueType, NewIssue, Project, Version};
+use crate::Api;
+use crate::Client;
+use crate::Error;
+use crate::Query;
+use crate::Response;
+use crate::Result;
+use crate::Deserialize;
+use std::cell::RefCell;
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+/// Obtained through `Project::context`, holds the project id and the issue
+/// types that can be created in the project according to its create meta.
+#[derive(Debug)]
+pub struct ProjectContext {
+    client: Client,
+
+    /// ID of the project
+    pub id: String,
+
+    /// Key of the project
+    pub key: String,
+
+    /// Issue types that can be created in the project
+    pub issue_types: Vec<IssueType>,
+
+    /// Reporter set on every issue created through the context
+    reporter: Option<::serde_json::Value>,
+
+    /// Components of the project once looked up
+    components: RefCell<Option<Vec<Component>>>,
+
+    /// Versions of the project once looked up
+    versions: RefCell<Option<Vec<Version>>>,
+}
+
+impl ProjectContext {
+    /// Fetches the create meta of a project. For more info consult the api
+    /// docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-getCreateIssueMeta
+    pub(crate) fn fetch(c: &Client, key: String) -> Result<ProjectContext> {
+        #[derive(Deserialize)]
+        struct Meta {
+            #[serde(default)]
+            projects: Vec<ProjectMeta>,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectMeta {
+            #[serde(default)]
+            id: String,
+            #[serde(default)]
+            key: String,
+            #[serde(default)]
+            issuetypes: Vec<IssueType>,
+        }
+
+        let mut query = Query::new();
+
+        query.insert("projectKeys", key.as_str());
+
+        let meta = c
+            .clone()
+            .add_query(query)
+            .get::<Meta>(&Api::V2.endpoint("issue/createmeta"))?
+            .data;
+
+        // JIRA leaves out projects the user can't create issues in
+        let project = meta
+            .projects
+            .into_iter()
+            .find(|p| p.key.eq_ignore_ascii_case(&key))
+            .ok_or(Error::NotFound)?;
+
+        Ok(ProjectContext {
+            client: c.clone(),
+            id: project.id,
+            key: project.key,
+            issue_types: project.issuetypes,
+            reporter: None,
+            components: RefCell::new(None),
+            versions: RefCell::new(None),
+        })
+    }
+
+    /// Sets the reporter of every issue created through the context given
+    /// their username
+    pub fn with_reporter<U>(mut self, username: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.reporter = Some(::serde_json::json!({ "name": username.into() }));
+        self
+    }
+
+    /// Returns the issue type with the given name or id, names are matched
+    /// ignoring case. Fails with `Error::InvalidIssueType` listing the issue
+    /// types of the project if there isn't one.
+    pub fn issue_type(&self, name_or_id: &str) -> Result<&IssueType> {
+        self.issue_types
+            .iter()
+            .find(|t| t.id == name_or_id || t.name.eq_ignore_ascii_case(name_or_id))
+            .ok_or_else(|| Error::InvalidIssueType {
+                name: name_or_id.to_string(),
+                available: self.issue_types.iter().map(|t| t.name.clone()).collect(),
+            })
+    }
+
+    /// Returns the id of the component with the given name, names are matched
+    /// ignoring case. The components of the project are fetched on the first
+    /// lookup, fails with `Error::ComponentNotFound` listing the components
+    /// of the project if there isn't one.
+    pub fn component_id(&self, name: &str) -> Result<String> {
+        if self.components.borrow().is_none() {
+            let components = Project::components(&self.client, self.key.as_str())?.data;
+            *self.components.borrow_mut() = Some(components);
+        }
+
+        let components = self.components.borrow();
+        let components = components.as_ref().map(Vec::as_slice).unwrap_or_default();
+
+        components
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+            .map(|c| c.id.clone())
+            .ok_or_else(|| Error::ComponentNotFound {
+                name: name.to_string(),
+                available: components.iter().map(|c| c.name.clone()).collect(),
+            })
+    }
+
+    /// Returns the id of the version with the given name, names are matched
+    /// ignoring case. The versions of the project are fetched on the first
+    /// lookup, fails with `Error::VersionNotFound` listing the versions of
+    /// the project if there isn't one.
+    pub fn version_id(&self, name: &str) -> Result<String> {
+        if self.versions.borrow().is_none() {
+            let versions = Project::versions(&self.client, self.key.as_str())?.data;
+            *self.versions.borrow_mut() = Some(versions);
+        }
+
+        let versions = self.versions.borrow();
+        let versions = versions.as_ref().map(Vec::as_slice).unwrap_or_default();
+
+        versions
+            .iter()
+            .find(|v| v.name.eq_ignore_ascii_case(name))
+            .map(|v| v.id.clone())
+            .ok_or_else(|| Error::VersionNotFound {
+                name: name.to_string(),
+                available: versions.iter().map(|v| v.name.clone()).collect(),
+            })
+    }
+
+    /// Returns a new issue in the project with the issue type, summary and
+    /// reporter set, add any other fields before passing it to
+    /// `Issue::create`
+    pub fn new_issue<S>(&self, summary: S, issue_type: &str) -> Result<NewIssue>
+    where
+        S: Into<String>,
+    {
+        let issue_type = self.issue_type(issue_type)?;
+        let issue = NewIssue::new(self.id.as_str(), issue_type.id.as_str(), summary);
+
+        Ok(match self.reporter {
+            Some(ref reporter) => issue.set("reporter", reporter.clone()),
+            None => issue,
+        })
+    }
+
+    /// Creates an issue in the project, the issue type is checked against
+    /// the project before anything is sent
+    pub fn create_issue<S>(&self, summary: S, issue_type: &str) -> Response<CreatedIssue>
+    where
+        S: Into<String>,
+    {
+        Issue::create(&self.client, self.new_issue(summary, issue_type)?)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Credentials;
+
+    fn context() -> ProjectContext {
+        let issue_type = |id: &str, name: &str| {
+            serde_json::from_value::<IssueType>(::serde_json::json!({"id": id, "name": name}))
+                .unwrap()
+        };
+
+        ProjectContext {
+            client: Client::new("http://jira", Credentials::new_basic("a", "b").unwrap()),
+            id: "10000".to_string(),
+            key: "EX".to_string(),
+            issue_types: vec![issue_type("1", "Bug"), issue_type("3", "Task")],
+            reporter: None,
+            components: RefCell::new(None),
+            versions: RefCell::new(None),
+        }
+    }
+
+    #[test]
+    fn test_new_issue() {
+        let issue = context().with_reporter("fred").new_issue("Broken", "bug").unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&issue).unwrap(),
+            ::serde_json::json!({"fields": {
+                "project": {"id": "10000"},
+                "issuetype": {"id": "1"},
+                "summary": "Broken",
+                "reporter": {"name": "fred"}
+            }})
+        );
+    }
+
+    #[test]
+    fn test_invalid_issue_type() {
+        match context().new_issue("Broken", "Epic") {
+            Err(Error::InvalidIssueType { name, available }) => {
+                assert_eq!(name, "Epic");
+                assert_eq!(available, vec!["Bug", "Task"]);
+            }
+            r => panic!("expected InvalidIssueType, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_cached_version_id() {
+        let c = context();
+        let version = |id: &str, name: &str| {
+            serde_json::from_value::<Version>(::serde_json::json!({"id": id, "name": name}))
+                .unwrap()
+        };
+
+        // Nothing is fetched once the versions are known
+        *c.versions.borrow_mut() = Some(vec![version("10000", "1.0"), version("10001", "2.0")]);
+
+        assert_eq!(c.version_id("2.0").unwrap(), "10001");
+        match c.version_id("3.0") {
+            Err(Error::VersionNotFound { available, .. }) => {
+                assert_eq!(available, vec!["1.0", "2.0"])
+            }
+            r => panic!("expected VersionNotFound, got {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Project roles and the users and groups (actors) that belong to them
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
+use crate::Client;
+use crate::Query;
+use crate::Resp;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+use reqwest::header::HeaderMap;
+use std::collections::BTreeMap;
+
+// ============================================================================
+// Public Enums
+// ============================================================================
+/// A member of a project role as returned by JIRA, members are either
+/// individual users or whole groups.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RoleActor {
+    #[serde(rename = "atlassian-user-role-actor")]
+    User(RoleActorDetails),
+
+    #[serde(rename = "atlassian-group-role-actor")]
+    Group(RoleActorDetails),
+
+    /// An actor type this library does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// Refers to a user or group when adding or removing role members
+pub enum RoleActorRef {
+    /// Username of the user
+    User(String),
+
+    /// Name of the group
+    Group(String),
+}
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleActorDetails {
+    /// ID of the actor
+    #[serde(default)]
+    pub id: i64,
+
+    /// Display name of the user or group
+    #[serde(rename = "displayName", default)]
+    pub display_name: String,
+
+    /// Username or group name
+    #[serde(default)]
+    pub name: String,
+
+    /// URL to the avatar of the actor
+    #[serde(rename = "avatarUrl", default)]
+    pub avatar_url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectRole {
+    /// REST API link to the project role
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// Name of the project role
+    #[serde(default)]
+    pub name: String,
+
+    /// ID of the project role
+    #[serde(default)]
+    pub id: i64,
+
+    /// Description of the project role
+    #[serde(default)]
+    pub description: String,
+
+    /// Users and groups belonging to the role
+    #[serde(default)]
+    pub actors: Vec<RoleActor>,
+}
+
+impl ProjectRole {
+    /// Fetches the roles available in a project as a map of role name to
+    /// REST API link. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project/{projectIdOrKey}/role-getProjectRoles
+    pub fn for_project<K>(c: &Client, project_key: K) -> Response<BTreeMap<String, String>>
+    where
+        K: Into<String>,
+    {
+        let endpoint = format!("{}/project/{}/role", Api::V2, encode_segment(&project_key.into()));
+        c.get(&endpoint)
+    }
+
+    /// Fetches a role in a project including its actors. For more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project/{projectIdOrKey}/role-getProjectRole
+    pub fn get<K, I>(c: &Client, project_key: K, role_id: I) -> Response<ProjectRole>
+    where
+        K: Into<String>,
+        I: Into<String>,
+    {
+        let endpoint = format!(
+            "{}/project/{}/role/{}",
+            Api::V2,
+            encode_segment(&project_key.into()),
+            encode_segment(&role_id.into())
+        );
+        c.get(&endpoint)
+    }
+
+    /// Adds users and groups to a role in a project. For more info consult
+    /// the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project/{projectIdOrKey}/role-addActorUsers
+    pub fn add_actors<K, I>(
+        c: &Client,
+        project_key: K,
+        role_id: I,
+        actors: Vec<RoleActorRef>,
+    ) -> Response<ProjectRole>
+    where
+        K: Into<String>,
+        I: Into<String>,
+    {
+        #[derive(Serialize, Default)]
+        struct Request {
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            user: Vec<String>,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            group: Vec<String>,
+        }
+
+        let endpoint = format!(
+            "{}/project/{}/role/{}",
+            Api::V2,
+            encode_segment(&project_key.into()),
+            encode_segment(&role_id.into())
+        );
+        let body = actors
+            .into_iter()
+            .fold(Request::default(), |mut acc, a| {
+                match a {
+                    RoleActorRef::User(u) => acc.user.push(u),
+                    RoleActorRef::Group(g) => acc.group.push(g),
+                }
+                acc
+            });
+
+        c.post(&endpoint, body)
+    }
+
+    /// Removes users and groups from a role in a project, JIRA only allows a
+    /// single actor to be removed per request so one request is sent for each
+    /// actor. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project/{projectIdOrKey}/role-deleteActor
+    pub fn remove_actors<K, I>(
+        c: &Client,
+        project_key: K,
+        role_id: I,
+        actors: Vec<RoleActorRef>,
+    ) -> Response<()>
+    where
+        K: Into<String>,
+        I: Into<String>,
+    {
+        let endpoint = format!(
+            "{}/project/{}/role/{}",
+            Api::V2,
+            encode_segment(&project_key.into()),
+            encode_segment(&role_id.into())
+        );
+
+        actors.into_iter().try_fold(
+            Resp {
+                data: (),
+                headers: HeaderMap::new(),
+            },
+            |_, a| {
+                let mut query = Query::new();
+
+                match a {
+                    RoleActorRef::User(u) => query.insert("user".to_string(), u),
+                    RoleActorRef::Group(g) => query.insert("group".to_string(), g),
+                };
+
+                c.clone().add_query(query).delete(&endpoint)
+            },
+        )
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for ProjectRole {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/project_role.json")
+            .expect("Unable to read in JSON file");
+        let r: ProjectRole = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(r.name, "Developers");
+        assert_eq!(r.id, 10360);
+        assert_eq!(r.actors.len(), 2);
+
+        match &r.actors[0] {
+            RoleActor::Group(g) => assert_eq!(g.name, "jira-developers"),
+            a => panic!("expected a group actor, got {:?}", a),
+        }
+
+        match &r.actors[1] {
+            RoleActor::User(u) => assert_eq!(u.name, "fred"),
+            a => panic!("expected a user actor, got {:?}", a),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_unknown_actor() {
+        let a: RoleActor = serde_json::from_str(r#"{"type": "app-role-actor", "id": 1}"#).unwrap();
+
+        assert!(matches!(a, RoleActor::Unknown));
+    }
+}
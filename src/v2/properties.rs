@@ -0,0 +1,187 @@
+//! Entity properties store arbitrary JSON against issues, projects, users and
+//! comments. Every entity exposes the same operations under its own path so
+//! they share a single implementation here.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
+use crate::Client;
+use crate::Query;
+use crate::Resp;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Enums
+// ============================================================================
+/// The kinds of entity that can hold properties
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PropertyEntity {
+    /// An issue given its id or key
+    Issue,
+
+    /// A project given its id or key
+    Project,
+
+    /// A user given their username
+    User,
+
+    /// A comment given its id
+    Comment,
+}
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Property {
+    /// Key of the property
+    #[serde(default)]
+    pub key: String,
+
+    /// Value stored against the key
+    #[serde(default)]
+    pub value: ::serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertyKey {
+    /// REST API link to the property
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// Key of the property
+    #[serde(default)]
+    pub key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertyKeys {
+    /// Keys of the properties on the entity
+    #[serde(default)]
+    pub keys: Vec<PropertyKey>,
+}
+
+pub struct Properties;
+
+impl Properties {
+    /// Fetches the keys of every property on an entity. For more info consult
+    /// the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue/{issueIdOrKey}/properties-getPropertiesKeys
+    pub fn keys<I>(c: &Client, entity: PropertyEntity, id: I) -> Response<Vec<String>>
+    where
+        I: Into<String>,
+    {
+        let (endpoint, query) = path(entity, &id.into(), None);
+        let Resp { data, headers } = c.clone().add_query(query).get::<PropertyKeys>(&endpoint)?;
+
+        Ok(Resp {
+            data: data.keys.into_iter().map(|k| k.key).collect(),
+            headers,
+        })
+    }
+
+    /// Fetches the value of a property on an entity, a missing property gives
+    /// `Error::NotFound`. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue/{issueIdOrKey}/properties-getProperty
+    pub fn get<I, K>(
+        c: &Client,
+        entity: PropertyEntity,
+        id: I,
+        key: K,
+    ) -> Response<::serde_json::Value>
+    where
+        I: Into<String>,
+        K: Into<String>,
+    {
+        let (endpoint, query) = path(entity, &id.into(), Some(&key.into()));
+        let Resp { data, headers } = c.clone().add_query(query).get::<Property>(&endpoint)?;
+
+        Ok(Resp {
+            data: data.value,
+            headers,
+        })
+    }
+
+    /// Sets the value of a property on an entity, creating the property if it
+    /// doesn't exist. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue/{issueIdOrKey}/properties-setProperty
+    pub fn set<I, K, V>(c: &Client, entity: PropertyEntity, id: I, key: K, value: V) -> Response<()>
+    where
+        I: Into<String>,
+        K: Into<String>,
+        V: Serialize,
+    {
+        let (endpoint, query) = path(entity, &id.into(), Some(&key.into()));
+        c.clone().add_query(query).put(&endpoint, value)
+    }
+
+    /// Deletes a property from an entity. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue/{issueIdOrKey}/properties-deleteProperty
+    pub fn delete<I, K>(c: &Client, entity: PropertyEntity, id: I, key: K) -> Response<()>
+    where
+        I: Into<String>,
+        K: Into<String>,
+    {
+        let (endpoint, query) = path(entity, &id.into(), Some(&key.into()));
+        c.clone().add_query(query).delete(&endpoint)
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Property {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+/// Builds the endpoint of the properties of an entity, or of a single
+/// property when a key is given. Users are identified in the query string
+/// rather than the path.
+fn path(entity: PropertyEntity, id: &str, key: Option<&str>) -> (String, Query) {
+    let mut query = Query::new();
+    let base = match entity {
+        PropertyEntity::Issue => format!("{}/issue/{}/properties", Api::V2, encode_segment(id)),
+        PropertyEntity::Project => format!("{}/project/{}/properties", Api::V2, encode_segment(id)),
+        PropertyEntity::Comment => format!("{}/comment/{}/properties", Api::V2, encode_segment(id)),
+        PropertyEntity::User => {
+            query.insert("username", id);
+            Api::V2.endpoint("user/properties")
+        }
+    };
+    let endpoint = match key {
+        Some(key) => format!("{}/{}", base, encode_segment(key)),
+        None => base,
+    };
+
+    (endpoint, query)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let (endpoint, query) = path(PropertyEntity::Issue, "EX-1", Some("app state"));
+
+        assert_eq!(endpoint, "api/2/issue/EX-1/properties/app%20state");
+        assert!(query.is_empty());
+
+        let (endpoint, query) = path(PropertyEntity::User, "fred", None);
+
+        assert_eq!(endpoint, "api/2/user/properties");
+        assert_eq!(query.get("username"), Some("fred"));
+    }
+}
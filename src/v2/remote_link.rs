@@ -0,0 +1,263 @@
+//! Remote issue links, these link an issue to something outside of JIRA such
+//! as a CI build, a pull request or a support ticket.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
+use crate::Client;
+use crate::Query;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RemoteLinkIcon {
+    /// URL to a 16x16 icon
+    #[serde(rename = "url16x16", default)]
+    pub url: String,
+
+    /// Text shown when hovering over the icon
+    #[serde(default)]
+    pub title: String,
+
+    /// Link followed when clicking the icon
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RemoteLinkStatus {
+    /// Is the remote object resolved? Resolved links are shown struck through
+    #[serde(default)]
+    pub resolved: bool,
+
+    /// Icon representing the status of the remote object
+    #[serde(default)]
+    pub icon: Option<RemoteLinkIcon>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RemoteLinkApplication {
+    /// Namespaced type of the application e.g. "com.acme.tracker"
+    #[serde(rename = "type", default)]
+    pub application_type: String,
+
+    /// Human readable name of the application
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RemoteLinkObject {
+    /// URL of the remote object
+    #[serde(default)]
+    pub url: String,
+
+    /// Title shown for the link
+    #[serde(default)]
+    pub title: String,
+
+    /// Summary shown next to the title
+    #[serde(default)]
+    pub summary: String,
+
+    /// Icon shown next to the link
+    #[serde(default)]
+    pub icon: Option<RemoteLinkIcon>,
+
+    /// Status of the remote object
+    #[serde(default)]
+    pub status: Option<RemoteLinkStatus>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RemoteLink {
+    /// ID of the remote link, assigned by JIRA
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub id: i64,
+
+    /// REST API link to the remote link
+    #[serde(rename = "self", default, skip_serializing_if = "String::is_empty")]
+    pub self_link: String,
+
+    /// A globally unique identifier for the remote object, if it is supplied
+    /// on create any existing link on the issue with the same global id is
+    /// updated instead of a new link being added.
+    #[serde(rename = "globalId", default, skip_serializing_if = "String::is_empty")]
+    pub global_id: String,
+
+    /// The application the remote object belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application: Option<RemoteLinkApplication>,
+
+    /// Describes the relationship between the issue and the object e.g.
+    /// "causes"
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub relationship: String,
+
+    /// The remote object itself
+    #[serde(default)]
+    pub object: RemoteLinkObject,
+}
+
+/// Returned by JIRA when a remote link is created or updated
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteLinkRef {
+    /// ID of the remote link
+    #[serde(default)]
+    pub id: i64,
+
+    /// REST API link to the remote link
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+}
+
+impl RemoteLink {
+    /// Returns a remote link pointing at the given url
+    pub fn new<U, T>(url: U, title: T) -> Self
+    where
+        U: Into<String>,
+        T: Into<String>,
+    {
+        RemoteLink {
+            object: RemoteLinkObject {
+                url: url.into(),
+                title: title.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Fetches all remote links on an issue. For more info consult the api
+    /// docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-getRemoteIssueLinks
+    pub fn list<K>(c: &Client, issue_key: K) -> Response<Vec<RemoteLink>>
+    where
+        K: Into<String>,
+    {
+        let url = format!("{}/issue/{}/remotelink", Api::V2, encode_segment(&issue_key.into()));
+        c.get(&url)
+    }
+
+    /// Fetches a single remote link on an issue by its id. For more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-getRemoteIssueLinkById
+    pub fn from_id<K, I>(c: &Client, issue_key: K, id: I) -> Response<RemoteLink>
+    where
+        K: Into<String>,
+        I: Into<String>,
+    {
+        let url = format!(
+            "{}/issue/{}/remotelink/{}",
+            Api::V2,
+            encode_segment(&issue_key.into()),
+            encode_segment(&id.into())
+        );
+        c.get(&url)
+    }
+
+    /// Creates a remote link on an issue. If the link carries a `global_id`
+    /// and a link with the same global id already exists on the issue that
+    /// link is replaced rather than a duplicate being created, this makes it
+    /// safe to call repeatedly for the same remote object. For more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-createOrUpdateRemoteIssueLink
+    pub fn create<K>(c: &Client, issue_key: K, link: &RemoteLink) -> Response<RemoteLinkRef>
+    where
+        K: Into<String>,
+    {
+        let url = format!("{}/issue/{}/remotelink", Api::V2, encode_segment(&issue_key.into()));
+        c.create(&url, link)
+    }
+
+    /// Deletes a remote link on an issue by its id. For more info consult the
+    /// api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-deleteRemoteIssueLinkById
+    pub fn delete_by_id<K, I>(c: &Client, issue_key: K, id: I) -> Response<()>
+    where
+        K: Into<String>,
+        I: Into<String>,
+    {
+        let url = format!(
+            "{}/issue/{}/remotelink/{}",
+            Api::V2,
+            encode_segment(&issue_key.into()),
+            encode_segment(&id.into())
+        );
+        c.delete(&url)
+    }
+
+    /// Deletes a remote link on an issue by its global id. For more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-deleteRemoteIssueLinkByGlobalId
+    pub fn delete_by_global_id<K, G>(c: &Client, issue_key: K, global_id: G) -> Response<()>
+    where
+        K: Into<String>,
+        G: Into<String>,
+    {
+        let url = format!("{}/issue/{}/remotelink", Api::V2, encode_segment(&issue_key.into()));
+        let mut query = Query::new();
+
+        query.insert("globalId", global_id);
+
+        c.clone().add_query(query).delete(&url)
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for RemoteLink {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+fn is_zero(n: &i64) -> bool {
+    *n == 0
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/remote_link.json")
+            .expect("Unable to read in JSON file");
+        let links: Vec<RemoteLink> = serde_json::from_str(&results).unwrap();
+        let l = &links[0];
+
+        assert_eq!(l.id, 10000);
+        assert_eq!(l.global_id, "system=http://www.mycompany.com/support&id=1");
+        assert_eq!(l.application.as_ref().unwrap().application_type, "com.acme.tracker");
+        assert_eq!(l.relationship, "causes");
+        assert_eq!(l.object.url, "http://www.mycompany.com/support?id=1");
+        assert_eq!(l.object.title, "TSTSUP-111");
+        assert_eq!(l.object.icon.as_ref().unwrap().title, "Support Ticket");
+        assert!(l.object.status.as_ref().unwrap().resolved);
+    }
+
+    #[test]
+    fn test_serialize_new_link_omits_id() {
+        let l = RemoteLink::new("http://ci.example.com/build/1", "Build #1");
+        let v = serde_json::to_value(&l).unwrap();
+
+        assert!(v.get("id").is_none());
+        assert!(v.get("self").is_none());
+        assert_eq!(v["object"]["url"], "http://ci.example.com/build/1");
+    }
+}
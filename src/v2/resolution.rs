@@ -1,8 +1,11 @@
-//! Represents a paginated list of users by group
+//! Resolutions describe why an issue was closed e.g. "Fixed" or "Won't Do",
+//! the ids differ between instances so look them up rather than hardcoding.
 
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
@@ -40,9 +43,16 @@ impl Resolution {
     where
         I: Into<String>,
     {
-        let url = format!("api/2/resolution/{}", id.into());
+        let url = format!("{}/resolution/{}", Api::V2, encode_segment(&id.into()));
         c.get(&url)
     }
+
+    /// Fetches every resolution on the instance. For more info consult the
+    /// api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/resolution-getResolutions
+    pub fn all(c: &Client) -> Response<Vec<Resolution>> {
+        c.get(&Api::V2.endpoint("resolution"))
+    }
 }
 
 // ============================================================================
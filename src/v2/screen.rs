@@ -0,0 +1,174 @@
+//! Screens decide which fields are shown when an issue is created, edited or
+//! viewed, grouped into tabs. Unlike the create meta which lists the fields
+//! an issue may have, a screen lists the fields a user actually sees, so the
+//! two together describe a form. Reading screens requires the Administer
+//! Jira global permission.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::v2::Pagination;
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::Result;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Screen {
+    /// ID of the screen
+    #[serde(default)]
+    pub id: i64,
+
+    /// Name of the screen
+    #[serde(default)]
+    pub name: String,
+
+    /// Description of the screen
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedScreens {
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// Screens on this page
+    #[serde(default)]
+    pub values: Vec<Screen>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScreenTab {
+    /// ID of the tab
+    #[serde(default)]
+    pub id: i64,
+
+    /// Name of the tab
+    #[serde(default)]
+    pub name: String,
+}
+
+/// A field shown on a tab of a screen
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScreenField {
+    /// ID of the field e.g. "summary" or "customfield_10010"
+    #[serde(default)]
+    pub id: String,
+
+    /// Name of the field
+    #[serde(default)]
+    pub name: String,
+}
+
+/// A tab of a screen along with its fields in the order they are shown
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScreenTabFields {
+    /// The tab
+    pub tab: ScreenTab,
+
+    /// Fields on the tab
+    pub fields: Vec<ScreenField>,
+}
+
+impl Screen {
+    /// Fetches a page of the screens on the instance, a user without admin
+    /// rights gets `Error::Forbidden`. For more info consult the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-screens/#api-rest-api-2-screens-get
+    pub fn all(c: &Client, page: Option<Pagination>) -> Response<PaginatedScreens> {
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let query = Client::unpack_options(vec![&page]);
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("screens"))
+    }
+
+    /// Fetches the tabs of a screen in the order they are shown. For more
+    /// info consult the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-screen-tabs/#api-rest-api-2-screens-screenid-tabs-get
+    pub fn tabs(c: &Client, screen_id: i64) -> Response<Vec<ScreenTab>> {
+        c.get(&format!("{}/screens/{}/tabs", Api::V2, screen_id))
+    }
+
+    /// Fetches the fields on a tab of a screen in the order they are shown.
+    /// For more info consult the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-screen-tab-fields/#api-rest-api-2-screens-screenid-tabs-tabid-fields-get
+    pub fn tab_fields(c: &Client, screen_id: i64, tab_id: i64) -> Response<Vec<ScreenField>> {
+        c.get(&format!("{}/screens/{}/tabs/{}/fields", Api::V2, screen_id, tab_id))
+    }
+
+    /// Fetches every tab of a screen along with its fields, one request is
+    /// made for the tabs and one for each tab
+    pub fn layout(c: &Client, screen_id: i64) -> Result<Vec<ScreenTabFields>> {
+        Screen::tabs(c, screen_id)?
+            .data
+            .into_iter()
+            .map(|tab| {
+                let fields = Screen::tab_fields(c, screen_id, tab.id)?.data;
+
+                Ok(ScreenTabFields { tab, fields })
+            })
+            .collect()
+    }
+}
+
+impl ScreenTabFields {
+    /// Returns true if the tab shows the field given its id
+    pub fn contains(&self, field_id: &str) -> bool {
+        self.fields.iter().any(|f| f.id == field_id)
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Screen {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/screens.json")
+            .expect("Unable to read in JSON file");
+        let s: PaginatedScreens = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(s.pagination.total, 2);
+        assert_eq!(s.values.len(), 2);
+        assert_eq!(s.values[0].id, 1);
+        assert_eq!(s.values[1].name, "Resolve Issue Screen");
+    }
+
+    #[test]
+    fn test_tab_contains() {
+        let fields: Vec<ScreenField> = serde_json::from_value(::serde_json::json!([
+            {"id": "summary", "name": "Summary"},
+            {"id": "customfield_10014", "name": "Epic Link"}
+        ]))
+        .unwrap();
+        let tab = ScreenTabFields {
+            tab: ScreenTab {
+                id: 10000,
+                name: "Field Tab".to_string(),
+            },
+            fields,
+        };
+
+        assert!(tab.contains("customfield_10014"));
+        assert!(!tab.contains("description"));
+    }
+}
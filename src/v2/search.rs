@@ -0,0 +1,201 @@
+//! Searching for issues in JIRA using JQL
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::v2::{Fields, Issue, Pagination};
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::Result;
+use crate::{Deserialize, Serialize};
+use std::io::Write;
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchResults {
+    /// Expand options that were applied to the results
+    #[serde(default)]
+    pub expand: String,
+
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// Issues matching the query
+    #[serde(default)]
+    pub issues: Vec<Issue>,
+}
+
+pub struct Search;
+
+impl Search {
+    /// Fetches a page of issues matching the given JQL. The query is sent in
+    /// the request body so it does not need to be encoded, pages larger than
+    /// `Pagination::MAX_SEARCH` are lowered to it. For more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/search-searchUsingSearchRequest
+    pub fn jql<J>(c: &Client, jql: J, page: Option<Pagination>) -> Response<SearchResults>
+    where
+        J: Into<String>,
+    {
+        search(c, jql.into(), None, page)
+    }
+
+    /// Fetches a page of issues matching the given JQL with only the given
+    /// fields, see `Search::jql`
+    pub fn jql_fields<J>(
+        c: &Client,
+        jql: J,
+        fields: &Fields,
+        page: Option<Pagination>,
+    ) -> Response<SearchResults>
+    where
+        J: Into<String>,
+    {
+        search(c, jql.into(), Some(fields), page)
+    }
+
+    /// Fetches every issue matching the given JQL by paging through the
+    /// results, `cap` guards against runaway queries by stopping once that
+    /// many issues have been collected. An error fetching any page is
+    /// returned instead of the issues collected so far.
+    pub fn jql_all<J>(c: &Client, jql: J, cap: Option<usize>) -> Result<Vec<Issue>>
+    where
+        J: Into<String>,
+    {
+        let jql = jql.into();
+
+        Pagination::collect_all(Pagination::default().max_results, cap, |page| {
+            let results = Search::jql(c, jql.as_str(), Some(page))?.data;
+            Ok((results.pagination, results.issues))
+        })
+    }
+
+    /// Writes every issue matching the given JQL to `writer` as newline
+    /// delimited JSON, one issue per line, paging through the results so only
+    /// a single page is held in memory. The writer is flushed after each page.
+    /// Returns the number of issues written along with the outcome, if a page
+    /// fails to fetch or write the issues written before it are left in place
+    /// so a partial export is still usable.
+    pub fn jql_export<J, W>(c: &Client, jql: J, writer: W) -> (usize, Result<()>)
+    where
+        J: Into<String>,
+        W: Write,
+    {
+        export(c, jql.into(), None, writer)
+    }
+
+    /// Writes every issue matching the given JQL with only the given fields
+    /// to `writer`, see `Search::jql_export`. Excluding `comment` and
+    /// `worklog` makes large exports much smaller.
+    pub fn jql_export_fields<J, W>(
+        c: &Client,
+        jql: J,
+        fields: &Fields,
+        writer: W,
+    ) -> (usize, Result<()>)
+    where
+        J: Into<String>,
+        W: Write,
+    {
+        export(c, jql.into(), Some(fields), writer)
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for SearchResults {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+/// Fetches a page of search results, JIRA's default fields are returned
+/// unless fields are given
+fn search(
+    c: &Client,
+    jql: String,
+    fields: Option<&Fields>,
+    page: Option<Pagination>,
+) -> Response<SearchResults> {
+    #[derive(Serialize)]
+    struct Request {
+        jql: String,
+        #[serde(rename = "startAt")]
+        start_at: i64,
+        #[serde(rename = "maxResults")]
+        max_results: i64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<String>>,
+    }
+
+    let page = Pagination::checked(page, Pagination::MAX_SEARCH)?;
+    let body = Request {
+        jql,
+        start_at: page.start_at,
+        max_results: page.max_results,
+        fields: fields.map(Fields::to_list),
+    };
+
+    c.post(&Api::V2.endpoint("search"), body)
+}
+
+/// Pages through search results writing each issue to `writer` as a line of
+/// JSON, see `Search::jql_export`
+fn export<W>(
+    c: &Client,
+    jql: String,
+    fields: Option<&Fields>,
+    mut writer: W,
+) -> (usize, Result<()>)
+where
+    W: Write,
+{
+    let max_results = Pagination::default().max_results;
+    let mut written = 0;
+
+    // Issues are written as each page arrives, only their count is collected
+    let result = Pagination::collect_all(max_results, None, |page| {
+        let results = search(c, jql.clone(), fields, Some(page))?.data;
+
+        for issue in &results.issues {
+            serde_json::to_writer(&mut writer, issue)?;
+            writer.write_all(b"\n")?;
+            written += 1;
+        }
+
+        writer.flush()?;
+
+        Ok((results.pagination, vec![(); results.issues.len()]))
+    });
+
+    (written, result.map(|_| ()))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results =
+            fs::read_to_string("tests/assets/v2/search.json").expect("Unable to read in JSON file");
+        let s: SearchResults = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(s.pagination.total, 1);
+        assert_eq!(s.pagination.max_results, 50);
+        assert_eq!(s.issues.len(), 1);
+    }
+}
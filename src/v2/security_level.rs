@@ -0,0 +1,94 @@
+//! Issue security levels restrict who can see an issue, the levels available
+//! depend on the security scheme of the project.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
+use crate::Client;
+use crate::Resp;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityLevel {
+    /// REST API link to the security level
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// ID of the security level
+    #[serde(default)]
+    pub id: String,
+
+    /// Name of the security level
+    #[serde(default)]
+    pub name: String,
+
+    /// Description of the security level
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityLevels {
+    /// Security levels the current user can set
+    #[serde(default)]
+    pub levels: Vec<SecurityLevel>,
+}
+
+impl SecurityLevel {
+    /// Fetches the security levels the current user can set on issues in a
+    /// project, use `IssueEdit::set_security` to apply one. For more info
+    /// consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/project/{projectKeyOrId}/securitylevel-getSecurityLevelsForProject
+    pub fn for_project<K>(c: &Client, project_key: K) -> Response<Vec<SecurityLevel>>
+    where
+        K: Into<String>,
+    {
+        let endpoint = format!(
+            "{}/project/{}/securitylevel",
+            Api::V2,
+            encode_segment(&project_key.into())
+        );
+        let Resp { data, headers } = c.get::<SecurityLevels>(&endpoint)?;
+
+        Ok(Resp {
+            data: data.levels,
+            headers,
+        })
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for SecurityLevel {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/security_levels.json")
+            .expect("Unable to read in JSON file");
+        let s: SecurityLevels = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(s.levels.len(), 2);
+        assert_eq!(s.levels[0].id, "100");
+        assert_eq!(s.levels[1].name, "Staff");
+    }
+}
@@ -0,0 +1,85 @@
+//! Details of the JIRA instance such as its version and deployment type, the
+//! endpoint doesn't need authentication so it is a cheap way to check an
+//! instance is up.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServerInfo {
+    /// Base URL of the instance
+    #[serde(rename = "baseUrl", default)]
+    pub base_url: String,
+
+    /// Version of JIRA e.g. "8.2.6"
+    #[serde(default)]
+    pub version: String,
+
+    /// Version of JIRA split into major, minor and patch numbers
+    #[serde(rename = "versionNumbers", default)]
+    pub version_numbers: Vec<i64>,
+
+    /// Either "Server" or "Cloud"
+    #[serde(rename = "deploymentType", default)]
+    pub deployment_type: String,
+
+    /// Build number of the version
+    #[serde(rename = "buildNumber", default)]
+    pub build_number: i64,
+
+    /// Title of the instance
+    #[serde(rename = "serverTitle", default)]
+    pub server_title: String,
+}
+
+impl ServerInfo {
+    /// Fetches the details of the instance. For more info consult the api
+    /// docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/serverInfo-getServerInfo
+    pub fn get(c: &Client) -> Response<ServerInfo> {
+        c.get(&Api::V2.endpoint("serverInfo"))
+    }
+
+    /// Returns true if the instance is JIRA Cloud
+    pub fn is_cloud(&self) -> bool {
+        self.deployment_type.eq_ignore_ascii_case("cloud")
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for ServerInfo {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/server_info.json")
+            .expect("Unable to read in JSON file");
+        let s: ServerInfo = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(s.version, "8.2.6");
+        assert_eq!(s.version_numbers, vec![8, 2, 6]);
+        assert!(!s.is_cloud());
+    }
+}
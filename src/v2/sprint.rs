@@ -0,0 +1,149 @@
+//! Sprints from the JIRA Software (Agile) API, sprints belong to a scrum
+//! board and hold the issues planned for an iteration.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::client::encode_segment;
+use crate::v2::{Pagination, SearchResults};
+use crate::Api;
+use crate::Client;
+use crate::Response;
+use crate::{Deserialize, Serialize};
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Sprint {
+    /// ID of the sprint
+    #[serde(default)]
+    pub id: i64,
+
+    /// REST API link to the sprint
+    #[serde(rename = "self", default)]
+    pub self_link: String,
+
+    /// State of the sprint, one of "future", "active" or "closed"
+    #[serde(default)]
+    pub state: String,
+
+    /// Name of the sprint
+    #[serde(default)]
+    pub name: String,
+
+    /// When the sprint was started
+    #[serde(rename = "startDate", default)]
+    pub start_date: String,
+
+    /// When the sprint is planned to end
+    #[serde(rename = "endDate", default)]
+    pub end_date: String,
+
+    /// When the sprint was completed
+    #[serde(rename = "completeDate", default)]
+    pub complete_date: String,
+
+    /// ID of the board the sprint was created on
+    #[serde(rename = "originBoardId", default)]
+    pub origin_board_id: i64,
+
+    /// Goal of the sprint
+    #[serde(default)]
+    pub goal: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedSprints {
+    /// Pagination fields
+    #[serde(flatten, default)]
+    pub pagination: Pagination,
+
+    /// Sprints on this page
+    #[serde(default)]
+    pub values: Vec<Sprint>,
+}
+
+impl Sprint {
+    /// Fetches a page of the sprints on a board. For more info consult the
+    /// api docs:
+    /// https://docs.atlassian.com/jira-software/REST/8.2.6/#agile/1.0/board/{boardId}/sprint-getAllSprints
+    pub fn for_board<I>(
+        c: &Client,
+        board_id: I,
+        page: Option<Pagination>,
+    ) -> Response<PaginatedSprints>
+    where
+        I: Into<String>,
+    {
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let query = Client::unpack_options(vec![&page]);
+        let endpoint = format!("{}/board/{}/sprint", Api::Agile, encode_segment(&board_id.into()));
+
+        c.clone().add_query(query).get(&endpoint)
+    }
+
+    /// Fetches a page of the issues in a sprint. For more info consult the
+    /// api docs:
+    /// https://docs.atlassian.com/jira-software/REST/8.2.6/#agile/1.0/sprint-getIssuesForSprint
+    pub fn issues<I>(c: &Client, sprint_id: I, page: Option<Pagination>) -> Response<SearchResults>
+    where
+        I: Into<String>,
+    {
+        let page = Pagination::checked(page, Pagination::MAX_DEFAULT)?;
+        let query = Client::unpack_options(vec![&page]);
+        let endpoint = format!("{}/sprint/{}/issue", Api::Agile, encode_segment(&sprint_id.into()));
+
+        c.clone().add_query(query).get(&endpoint)
+    }
+
+    /// Moves issues into a sprint, JIRA accepts at most 50 issues per
+    /// request. For more info consult the api docs:
+    /// https://docs.atlassian.com/jira-software/REST/8.2.6/#agile/1.0/sprint-moveIssuesToSprint
+    pub fn move_issues<I>(c: &Client, sprint_id: I, issue_keys: Vec<String>) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        #[derive(Serialize)]
+        struct Request {
+            issues: Vec<String>,
+        }
+
+        let endpoint = format!("{}/sprint/{}/issue", Api::Agile, encode_segment(&sprint_id.into()));
+
+        c.post(&endpoint, Request { issues: issue_keys })
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Sprint {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/sprints.json")
+            .expect("Unable to read in JSON file");
+        let s: PaginatedSprints = serde_json::from_str(&results).unwrap();
+
+        assert!(!s.pagination.is_last);
+        assert_eq!(s.pagination.max_results, 2);
+        assert_eq!(s.values.len(), 2);
+        assert_eq!(s.values[0].state, "closed");
+        assert_eq!(s.values[0].origin_board_id, 5);
+        assert_eq!(s.values[1].goal, "Ship the importer");
+    }
+}
@@ -1,9 +1,11 @@
-//! Represents an attachment in the JIRA system
+//! Represents a status in the JIRA system
 
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
 use crate::v2::StatusCategory;
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
@@ -48,9 +50,15 @@ impl Status {
     where
         I: Into<String>,
     {
-        let url = format!("api/2/status/{}", id.into());
+        let url = format!("{}/status/{}", Api::V2, encode_segment(&id.into()));
         c.get(&url)
     }
+
+    /// Returns true if the status is in the done category, statuses without
+    /// a category aren't done
+    pub fn is_done(&self) -> bool {
+        self.status_category.as_ref().map(|c| c.is_done()).unwrap_or(false)
+    }
 }
 
 // ============================================================================
@@ -3,10 +3,45 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
 use crate::Client;
 use crate::Response;
 use crate::{Deserialize, Serialize};
 
+// ============================================================================
+// Public Enums
+// ============================================================================
+/// The key of a status category, boards colour their columns by it. Keys
+/// this library doesn't know about are kept in `Unknown`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum StatusCategoryKey {
+    /// Work that hasn't started, "new"
+    New,
+
+    /// Work in progress, "indeterminate"
+    Indeterminate,
+
+    /// Finished work, "done"
+    Done,
+
+    /// Any other key, e.g. "undefined" for statuses without a category
+    Unknown(String),
+}
+
+impl StatusCategoryKey {
+    /// Returns the key as JIRA sends it
+    pub fn as_str(&self) -> &str {
+        match self {
+            StatusCategoryKey::New => "new",
+            StatusCategoryKey::Indeterminate => "indeterminate",
+            StatusCategoryKey::Done => "done",
+            StatusCategoryKey::Unknown(key) => key.as_str(),
+        }
+    }
+}
+
 // ============================================================================
 // Public Structures
 // ============================================================================
@@ -20,9 +55,9 @@ pub struct StatusCategory {
     #[serde(default)]
     pub id: i64,
 
-    /// Staus category key
+    /// Status category key
     #[serde(default)]
-    pub key: String,
+    pub key: StatusCategoryKey,
 
     /// Name of the colour given to the status category
     #[serde(rename = "colorName", default)]
@@ -31,7 +66,6 @@ pub struct StatusCategory {
     /// Name of the status category
     #[serde(default)]
     pub name: String,
-
 }
 
 impl StatusCategory {
@@ -44,14 +78,51 @@ impl StatusCategory {
     where
         I: Into<String>,
     {
-        let url = format!("api/2/statuscategory/{}", id.into());
+        let url = format!("{}/statuscategory/{}", Api::V2, encode_segment(&id.into()));
         c.get(&url)
     }
+
+    /// Returns true if statuses in the category count as finished work
+    pub fn is_done(&self) -> bool {
+        self.key == StatusCategoryKey::Done
+    }
 }
 
 // ============================================================================
 // Trait Implementations
 // ============================================================================
+impl Default for StatusCategoryKey {
+    fn default() -> Self {
+        StatusCategoryKey::Unknown(String::new())
+    }
+}
+
+impl From<String> for StatusCategoryKey {
+    fn from(key: String) -> Self {
+        match key.as_str() {
+            "new" => StatusCategoryKey::New,
+            "indeterminate" => StatusCategoryKey::Indeterminate,
+            "done" => StatusCategoryKey::Done,
+            _ => StatusCategoryKey::Unknown(key),
+        }
+    }
+}
+
+impl From<StatusCategoryKey> for String {
+    fn from(key: StatusCategoryKey) -> Self {
+        match key {
+            StatusCategoryKey::Unknown(key) => key,
+            key => key.as_str().to_string(),
+        }
+    }
+}
+
+impl PartialEq<&str> for StatusCategoryKey {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
 impl std::fmt::Display for StatusCategory {
     // This trait requires fmt with this signature
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -78,5 +149,18 @@ mod tests {
         assert_eq!(s.key, "in-flight");
         assert_eq!(s.colour_name, "yellow");
         assert_eq!(s.name, "In Progress");
+        assert!(!s.is_done());
+    }
+
+    #[test]
+    fn test_key() {
+        let key: StatusCategoryKey = serde_json::from_str(r#""done""#).unwrap();
+
+        assert_eq!(key, StatusCategoryKey::Done);
+        assert_eq!(serde_json::to_string(&key).unwrap(), r#""done""#);
+        assert_eq!(
+            serde_json::from_str::<StatusCategoryKey>(r#""undefined""#).unwrap(),
+            StatusCategoryKey::Unknown("undefined".to_string())
+        );
     }
 }
@@ -0,0 +1,236 @@
+//! Workflow transitions move an issue between statuses, the transitions
+//! available depend on the workflow and the current status of the issue.
+
+// ============================================================================
+// Use
+// ============================================================================
+use crate::v2::{Resolution, Status};
+use crate::Error;
+use crate::Result;
+use crate::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// ============================================================================
+// Public Enums
+// ============================================================================
+/// Refers to a transition by id or by name. Ids are only unique within a
+/// workflow, so when issues in different workflows are transitioned the
+/// name is usually what stays the same.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransitionRef {
+    /// ID of the transition
+    Id(String),
+
+    /// Name of the transition, matched ignoring case
+    Name(String),
+}
+
+impl TransitionRef {
+    /// Returns the transition referred to among the transitions available
+    /// to an issue. Fails with `Error::TransitionNotFound` listing the names
+    /// of the available transitions if it isn't one of them, which is the
+    /// case when the workflow doesn't allow it from the current status.
+    pub fn find<'a>(&self, transitions: &'a [Transition]) -> Result<&'a Transition> {
+        let (name, found) = match self {
+            TransitionRef::Id(id) => (id, transitions.iter().find(|t| &t.id == id)),
+            TransitionRef::Name(name) => (
+                name,
+                transitions.iter().find(|t| t.name.eq_ignore_ascii_case(name)),
+            ),
+        };
+
+        found.ok_or_else(|| Error::TransitionNotFound {
+            name: name.to_string(),
+            available: transitions.iter().map(|t| t.name.clone()).collect(),
+        })
+    }
+}
+
+// ============================================================================
+// Public Structures
+// ============================================================================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Transition {
+    /// ID of the transition, only unique within a workflow
+    #[serde(default)]
+    pub id: String,
+
+    /// Name of the transition e.g. "Start Progress"
+    #[serde(default)]
+    pub name: String,
+
+    /// The status the issue will be in after the transition
+    #[serde(default)]
+    pub to: Option<Status>,
+
+    /// Does the transition show a screen in the browser
+    #[serde(rename = "hasScreen", default)]
+    pub has_screen: bool,
+
+    /// Fields that can be set during the transition keyed by field id, only
+    /// returned when the transitions are fetched with their fields
+    #[serde(default)]
+    pub fields: BTreeMap<String, ::serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Transitions {
+    /// Transitions available to the issue in its current status
+    #[serde(default)]
+    pub transitions: Vec<Transition>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TransitionId {
+    id: String,
+}
+
+/// Describes a transition to perform on an issue along with any fields to
+/// set as part of it, such as the resolution when closing an issue.
+///
+/// Examples:
+/// IssueTransition::new("31").set_resolution(&fixed)
+/// IssueTransition::new("31").set_field("assignee", json!({"name": "fred"}))
+/// IssueTransition::new("31").notify_users(false)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct IssueTransition {
+    transition: TransitionId,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    fields: BTreeMap<String, ::serde_json::Value>,
+
+    #[serde(skip)]
+    notify_users: Option<bool>,
+}
+
+impl IssueTransition {
+    /// Returns a transition with no fields given the id of the transition
+    pub fn new<I>(id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        IssueTransition {
+            transition: TransitionId { id: id.into() },
+            ..Default::default()
+        }
+    }
+
+    /// Returns the id of the transition to perform
+    pub fn id(&self) -> &str {
+        self.transition.id.as_str()
+    }
+
+    /// Returns whether watchers are to be notified, None if left to JIRA
+    pub(crate) fn notify(&self) -> Option<bool> {
+        self.notify_users
+    }
+
+    /// Sets a field on the screen of the transition
+    pub fn set_field<F, V>(mut self, field: F, value: V) -> Self
+    where
+        F: Into<String>,
+        V: Into<::serde_json::Value>,
+    {
+        self.fields.insert(field.into(), value.into());
+        self
+    }
+
+    /// Whether watchers are emailed about the transition, sent as
+    /// `notifyUsers` alongside the transition
+    pub fn notify_users(mut self, notify: bool) -> Self {
+        self.notify_users = Some(notify);
+        self
+    }
+
+    /// Sets the resolution of the issue, see `Resolution::all` for the
+    /// resolutions available on an instance
+    pub fn set_resolution(self, resolution: &Resolution) -> Self {
+        self.set_field("resolution", ::serde_json::json!({ "id": resolution.id }))
+    }
+}
+
+/// Options for `Issue::transition_bulk`
+#[derive(Clone, Debug, Default)]
+pub struct BulkTransitionOptions {
+    /// Whether watchers are emailed about each transition, None leaves it
+    /// to JIRA
+    pub notify_users: Option<bool>,
+
+    /// Stop at the first issue that fails, the issues after it are reported
+    /// as `Error::NotAttempted`
+    pub stop_on_error: bool,
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+impl std::fmt::Display for Transition {
+    // This trait requires fmt with this signature
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results = fs::read_to_string("tests/assets/v2/transitions.json")
+            .expect("Unable to read in JSON file");
+        let t: Transitions = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(t.transitions.len(), 2);
+        assert_eq!(t.transitions[0].name, "Start Progress");
+        assert_eq!(t.transitions[0].to.as_ref().unwrap().name, "In Progress");
+        assert!(t.transitions[1].has_screen);
+        assert!(t.transitions[1].fields.contains_key("resolution"));
+    }
+
+    #[test]
+    fn test_serialize_resolution() {
+        let resolution = Resolution {
+            self_link: String::new(),
+            id: "10001".to_string(),
+            description: String::new(),
+            name: "Won't Do".to_string(),
+        };
+        let t = IssueTransition::new("31").set_resolution(&resolution);
+
+        assert_eq!(
+            serde_json::to_value(&t).unwrap(),
+            ::serde_json::json!({
+                "transition": {"id": "31"},
+                "fields": {"resolution": {"id": "10001"}}
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(IssueTransition::new("11")).unwrap(),
+            ::serde_json::json!({"transition": {"id": "11"}})
+        );
+    }
+
+    #[test]
+    fn test_find_transition() {
+        let results = fs::read_to_string("tests/assets/v2/transitions.json")
+            .expect("Unable to read in JSON file");
+        let t: Transitions = serde_json::from_str(&results).unwrap();
+        let by_name = TransitionRef::Name("start progress".to_string());
+        let by_id = TransitionRef::Id(t.transitions[1].id.clone());
+
+        assert_eq!(by_name.find(&t.transitions).unwrap().name, "Start Progress");
+        assert_eq!(by_id.find(&t.transitions).unwrap().name, "Done");
+        match TransitionRef::Name("Reopen".to_string()).find(&t.transitions) {
+            Err(Error::TransitionNotFound { name, available }) => {
+                assert_eq!(name, "Reopen");
+                assert_eq!(available, vec!["Start Progress", "Done"]);
+            }
+            r => panic!("expected TransitionNotFound, got {:?}", r),
+        }
+    }
+}
@@ -3,13 +3,18 @@
 // ============================================================================
 // Use
 // ============================================================================
-use crate::v2::{ApplicationRole, Group, Item, Pagination};
+use crate::v2::avatar;
+use crate::v2::item::{deserialize_items, serialize_items};
+use crate::v2::{ApplicationRole, AvatarCrop, Group, Pagination};
+use crate::Api;
 use crate::Client;
+use crate::Error;
 use crate::Options;
+use crate::Query;
 use crate::Response;
+use crate::Result;
 use crate::{Deserialize, Serialize};
 use std::collections::BTreeMap;
-use std::collections::HashMap;
 
 // ============================================================================
 // Public Enums
@@ -28,6 +33,15 @@ impl UserExpand {
     }
 }
 
+/// What a user must be assignable to, see `User::assignable`
+pub enum AssignableTo {
+    /// Issues in a project given its key
+    Project(String),
+
+    /// An existing issue given its key
+    Issue(String),
+}
+
 // ============================================================================
 // Public Structures
 // ============================================================================
@@ -40,8 +54,8 @@ pub struct UserOptions {
 }
 
 impl Options for UserOptions {
-    fn to_query(&self) -> HashMap<String, String> {
-        let mut h = HashMap::new();
+    fn to_query(&self) -> Query {
+        let mut h = Query::new();
         h.insert(
             String::from("includeInactive"),
             self.include_inactive.to_string(),
@@ -72,7 +86,7 @@ impl Default for UserOptions {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct User {
     /// Returns true if the user is active in Jira
     #[serde(default)]
@@ -94,6 +108,11 @@ pub struct User {
     #[serde(default)]
     pub key: String,
 
+    /// The account id of the user, JIRA Cloud identifies users by this
+    /// rather than username or key
+    #[serde(rename = "accountId", default)]
+    pub account_id: String,
+
     /// The username of the user
     #[serde(default)]
     pub name: String,
@@ -106,13 +125,24 @@ pub struct User {
     #[serde(rename = "timeZone", default)]
     pub timezone: String,
 
-    /// List of groups the user belongs to
-    #[serde()]
-    pub groups: Option<Item>,
-
-    /// List of application roles the user has
-    #[serde(rename = "applicationRoles")]
-    pub application_roles: Option<Item>,
+    /// List of groups the user belongs to, only populated when the groups
+    /// are expanded
+    #[serde(
+        default,
+        deserialize_with = "deserialize_items",
+        serialize_with = "serialize_items"
+    )]
+    pub groups: Vec<Group>,
+
+    /// List of application roles the user has, only populated when the
+    /// application roles are expanded
+    #[serde(
+        rename = "applicationRoles",
+        default,
+        deserialize_with = "deserialize_items",
+        serialize_with = "serialize_items"
+    )]
+    pub application_roles: Vec<ApplicationRole>,
 }
 
 impl User {
@@ -125,12 +155,52 @@ impl User {
     where
         S: Into<String>,
     {
-        let mut query =
-            Client::unpack_options(vec![&opts.unwrap_or_default(), &page.unwrap_or_default()]);
+        let page = Pagination::checked(page, Pagination::MAX_USERS)?;
+        let mut query = Client::unpack_options(vec![&opts.unwrap_or_default(), &page]);
 
         query.insert("username".to_string(), search.into());
 
-        c.clone().add_query(query).get("api/2/user/search")
+        c.clone().add_query(query).get(&Api::V2.endpoint("user/search"))
+    }
+
+    /// Searches for users on JIRA Cloud, the query is matched against the
+    /// display name and email address. For more info consult the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-user-search/#api-rest-api-2-user-search-get
+    pub fn search_cloud<Q>(c: &Client, query: Q, page: Option<Pagination>) -> Response<Vec<User>>
+    where
+        Q: Into<String>,
+    {
+        let page = Pagination::checked(page, Pagination::MAX_USERS)?;
+        let mut q = Client::unpack_options(vec![&page]);
+
+        q.insert("query".to_string(), query.into());
+
+        c.clone().add_query(q).get(&Api::V2.endpoint("user/search"))
+    }
+
+    /// Searches the users that can be assigned issues in a project or an
+    /// existing issue, the query is matched against the username, name and
+    /// email address. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/user-findAssignableUsers
+    pub fn assignable<S>(
+        c: &Client,
+        to: AssignableTo,
+        search: S,
+        page: Option<Pagination>,
+    ) -> Response<Vec<User>>
+    where
+        S: Into<String>,
+    {
+        let page = Pagination::checked(page, Pagination::MAX_USERS)?;
+        let mut query = Client::unpack_options(vec![&page]);
+
+        match to {
+            AssignableTo::Project(key) => query.insert("project", key),
+            AssignableTo::Issue(key) => query.insert("issueKey", key),
+        }
+        query.insert("username", search.into());
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("user/assignable/search"))
     }
 
     /// Fetches a user by username
@@ -138,12 +208,12 @@ impl User {
     where
         U: Into<String>,
     {
-        let mut query: HashMap<String, String> = HashMap::new();
+        let mut query = Query::new();
 
         query.insert("username".to_string(), username.into());
-        query.extend(expand_to_hashmap(expand));
+        query.extend(expand_to_query(expand));
 
-        c.clone().add_query(query).get("api/2/user")
+        c.clone().add_query(query).get(&Api::V2.endpoint("user"))
     }
 
     /// Fetches a user by key
@@ -151,46 +221,169 @@ impl User {
     where
         K: Into<String>,
     {
-        let mut query: HashMap<String, String> = HashMap::new();
+        let mut query = Query::new();
 
         query.insert("key".to_string(), key.into());
-        query.extend(expand_to_hashmap(expand));
+        query.extend(expand_to_query(expand));
 
-        c.clone().add_query(query).get("api/2/user")
+        c.clone().add_query(query).get(&Api::V2.endpoint("user"))
     }
 
-    pub fn groups(&self) -> Vec<Group> {
-        if let Some(i) = &self.groups {
-            serde_json::value::from_value(i.items.clone()).unwrap()
-        } else {
-            Vec::new()
-        }
+    /// Fetches a user by account id, used on JIRA Cloud where usernames and
+    /// keys are no longer available
+    pub fn from_account_id<A>(c: &Client, account_id: A, expand: Vec<UserExpand>) -> Response<User>
+    where
+        A: Into<String>,
+    {
+        let mut query = Query::new();
+
+        query.insert("accountId".to_string(), account_id.into());
+        query.extend(expand_to_query(expand));
+
+        c.clone().add_query(query).get(&Api::V2.endpoint("user"))
     }
 
-    pub fn application_roles(&self) -> Vec<ApplicationRole> {
-        if let Some(i) = &self.application_roles {
-            serde_json::value::from_value(i.items.clone()).unwrap()
-        } else {
-            Vec::new()
+    /// Fetches the user the client is authenticated as. For more info consult
+    /// the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/myself-getUser
+    pub fn myself(c: &Client) -> Response<User> {
+        c.get(&Api::V2.endpoint("myself"))
+    }
+
+    /// Looks up several users by username or key on JIRA Server, one request
+    /// is sent per identifier and a second by key if no user has it as their
+    /// username. The results are in the same order as the input, a user that
+    /// doesn't exist is returned as None and any other failure is returned
+    /// for that identifier alone so the rest of the batch is still looked up.
+    pub fn bulk<U>(c: &Client, keys_or_usernames: &[U]) -> Vec<(String, Result<Option<User>>)>
+    where
+        U: AsRef<str>,
+    {
+        keys_or_usernames
+            .iter()
+            .map(|u| {
+                let id = u.as_ref().to_string();
+                let user = match User::from_username(c, id.as_str(), vec![]) {
+                    Err(Error::NotFound) => User::from_key(c, id.as_str(), vec![]),
+                    r => r,
+                };
+                let user = match user {
+                    Ok(r) => Ok(Some(r.data)),
+                    Err(Error::NotFound) => Ok(None),
+                    Err(e) => Err(e),
+                };
+
+                (id, user)
+            })
+            .collect()
+    }
+
+    /// Looks up several users by account id on JIRA Cloud using the bulk
+    /// endpoint, ids are sent in batches of 200. The results are in the same
+    /// order as the input and an account id JIRA doesn't return a user for
+    /// is returned as None. For more info consult the api docs:
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-users/#api-rest-api-2-user-bulk-get
+    pub fn bulk_account_ids<A>(c: &Client, account_ids: &[A]) -> Result<Vec<(String, Option<User>)>>
+    where
+        A: AsRef<str>,
+    {
+        #[derive(Deserialize)]
+        struct Page {
+            #[serde(default)]
+            values: Vec<User>,
         }
+
+        let mut found: BTreeMap<String, User> = BTreeMap::new();
+
+        for chunk in account_ids.chunks(200) {
+            let mut query = Query::new();
+
+            query.insert("maxResults", chunk.len().to_string());
+            for id in chunk {
+                query.append("accountId", id.as_ref());
+            }
+
+            let page = c
+                .clone()
+                .add_query(query)
+                .get::<Page>(&Api::V2.endpoint("user/bulk"))?
+                .data;
+
+            found.extend(page.values.into_iter().map(|u| (u.account_id.clone(), u)));
+        }
+
+        Ok(account_ids
+            .iter()
+            .map(|id| {
+                let id = id.as_ref().to_string();
+                let user = found.get(&id).cloned();
+                (id, user)
+            })
+            .collect())
+    }
+
+    /// Uploads an image and makes it the avatar of the user returning the id
+    /// of the new avatar. Without a crop the largest square JIRA can fit in
+    /// the image is used. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/user-storeTemporaryAvatar
+    pub fn set_avatar<U>(
+        c: &Client,
+        username: U,
+        image: Vec<u8>,
+        content_type: &str,
+        crop: Option<AvatarCrop>,
+    ) -> Response<String>
+    where
+        U: Into<String>,
+    {
+        let mut owner = Query::new();
+
+        owner.insert("username".to_string(), username.into());
+
+        avatar::upload(c, &Api::V2.endpoint("user/avatar"), owner, image, content_type, crop)
+    }
+
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+
+    pub fn application_roles(&self) -> &[ApplicationRole] {
+        &self.application_roles
+    }
+
+    /// Returns the user as pretty printed JSON, which is what `Display` gave
+    /// before it printed a one line summary
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
     }
 }
 
 // ============================================================================
 // Trait Implementations
 // ============================================================================
+/// Prints a one line summary e.g. `User(jsmith) John Smith <john@x.com>`,
+/// users on JIRA Cloud are identified by their account id. See `to_json` for
+/// every field.
 impl std::fmt::Display for User {
     // This trait requires fmt with this signature
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
+        let id = if self.name.is_empty() { &self.account_id } else { &self.name };
+
+        write!(f, "User({}) {}", id, self.display_name)?;
+
+        if self.email_address.is_empty() {
+            Ok(())
+        } else {
+            write!(f, " <{}>", self.email_address)
+        }
     }
 }
 
 // ============================================================================
 // Private
 // ============================================================================
-fn expand_to_hashmap(e: Vec<UserExpand>) -> HashMap<String, String> {
-    let mut res: HashMap<String, String> = HashMap::new();
+fn expand_to_query(e: Vec<UserExpand>) -> Query {
+    let mut res = Query::new();
     let mut value = e.iter().fold(String::from(""), |acc, e| {
         format!("{}{},", acc, e.to_string())
     });
@@ -210,9 +403,9 @@ mod tests {
     use std::fs;
 
     #[test]
-    fn test_expand_to_hashmap() {
+    fn test_expand_to_query() {
         let e = vec![UserExpand::ApplicationRoles, UserExpand::Groups];
-        let h = expand_to_hashmap(e);
+        let h = expand_to_query(e);
 
         assert!(h.get("expand").unwrap().contains("groups"));
         assert!(h.get("expand").unwrap().contains("applicationRoles"));
@@ -236,4 +429,41 @@ mod tests {
             "http://www.example.com/jira/rest/api/2/user?username=fred"
         );
     }
+
+    #[test]
+    fn test_groups_envelope() {
+        let results =
+            fs::read_to_string("tests/assets/v2/user.json").expect("Unable to read in JSON file");
+        let user: User = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(user.groups[1].name, "jira-admin");
+
+        let v = serde_json::to_value(&user).unwrap();
+        assert_eq!(v["groups"]["size"], 3);
+        assert_eq!(v["groups"]["items"][0]["name"], "jira-user");
+    }
+
+    #[test]
+    fn test_groups_missing_or_null() {
+        let user: User = serde_json::from_str(r#"{"name": "fred", "groups": null}"#).unwrap();
+
+        assert!(user.groups().is_empty());
+        assert!(user.application_roles().is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let results =
+            fs::read_to_string("tests/assets/v2/user.json").expect("Unable to read in JSON file");
+        let mut user: User = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(user.to_string(), "User(fred) Fred F. User <fred@example.com>");
+        assert!(user.to_json().contains("\"emailAddress\": \"fred@example.com\""));
+
+        user.name = String::new();
+        user.account_id = "5b10ac8d82e05b22cc7d4ef5".to_string();
+        user.email_address = String::new();
+
+        assert_eq!(user.to_string(), "User(5b10ac8d82e05b22cc7d4ef5) Fred F. User");
+    }
 }
@@ -3,7 +3,10 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
+use crate::Api;
 use crate::Client;
+use crate::Precondition;
 use crate::Response;
 use crate::{Deserialize, Serialize};
 
@@ -87,19 +90,69 @@ impl Version {
     where
         I: Into<String>,
     {
-        let endpoint = format!("api/2/version/{}", id.into());
+        let endpoint = format!("{}/version/{}", Api::V2, encode_segment(&id.into()));
         c.get(&endpoint)
     }
 
     /// Updates a version
     pub fn update(self, c: &Client) -> Response<Version> {
-        let endpoint = format!("api/v2/version/{}", &self.id);
+        let endpoint = format!("{}/version/{}", Api::V2, encode_segment(&self.id));
         c.put(&endpoint, self)
     }
 
+    /// Updates a version only if the precondition holds, otherwise JIRA
+    /// rejects it with `Error::PreconditionFailed`
+    pub fn update_if(self, c: &Client, p: Precondition) -> Response<Version> {
+        self.update(&c.clone().precondition(Some(p)))
+    }
+
     /// Creates a new version
     pub fn create(self, c: &Client) -> Response<Version> {
-        c.post("api/2/version", self)
+        c.create(&Api::V2.endpoint("version"), self)
+    }
+
+    /// Deletes a version by id
+    pub fn delete<I>(c: &Client, id: I) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        let endpoint = format!("{}/version/{}", Api::V2, encode_segment(&id.into()));
+        c.delete(&endpoint)
+    }
+
+    /// Deletes this version
+    pub fn delete_self(&self, c: &Client) -> Response<()> {
+        Version::delete(c, self.id.as_str())
+    }
+
+    /// Deletes a version by id moving the issues that have it as a fix or
+    /// affected version to the given versions, a None leaves the field empty
+    /// on those issues. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/version-delete
+    pub fn delete_and_replace<I>(
+        c: &Client,
+        id: I,
+        move_fix_to: Option<String>,
+        move_affected_to: Option<String>,
+    ) -> Response<()>
+    where
+        I: Into<String>,
+    {
+        #[derive(Serialize)]
+        struct Request {
+            #[serde(rename = "moveFixIssuesTo", skip_serializing_if = "Option::is_none")]
+            move_fix_issues_to: Option<String>,
+            #[serde(rename = "moveAffectedIssuesTo", skip_serializing_if = "Option::is_none")]
+            move_affected_issues_to: Option<String>,
+        }
+
+        let endpoint = format!("{}/version/{}/removeAndSwap", Api::V2, encode_segment(&id.into()));
+        let body = Request {
+            move_fix_issues_to: move_fix_to,
+            move_affected_issues_to: move_affected_to,
+        };
+
+        c.post(&endpoint, body)
     }
 }
 
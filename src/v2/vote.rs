@@ -18,13 +18,14 @@ pub struct Vote {
 
     /// Number of votes for an issue
     #[serde(default)]
-    pub votes: i64,
+    pub votes: u64,
 
-    /// Have people voted
-    #[serde(default)]
+    /// Has the current user voted
+    #[serde(rename = "hasVoted", default)]
     pub has_voted: bool,
 
-    /// Generic list of items
+    /// Users who voted on the issue, this is only populated when the caller
+    /// has permission to view voters, otherwise it is left empty.
     #[serde(default)]
     pub voters: Vec<User>
 }
@@ -37,4 +38,33 @@ impl std::fmt::Display for Vote {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
     }
-}
\ No newline at end of file
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results =
+            fs::read_to_string("tests/assets/v2/vote.json").expect("Unable to read in JSON file");
+        let v: Vote = serde_json::from_str(&results).unwrap();
+
+        assert_eq!(v.self_link, "http://www.example.com/jira/rest/api/issue/MKY-1/votes");
+        assert_eq!(v.votes, 24);
+        assert!(v.has_voted);
+        assert_eq!(v.voters.len(), 1);
+    }
+
+    #[test]
+    fn test_deserialize_without_voters() {
+        let v: Vote = serde_json::from_str(r#"{"votes": 3, "hasVoted": false}"#).unwrap();
+
+        assert_eq!(v.votes, 3);
+        assert!(v.voters.is_empty());
+    }
+}
@@ -3,14 +3,106 @@
 // ============================================================================
 // Use
 // ============================================================================
+use crate::client::encode_segment;
 use crate::v2::{Pagination, User};
+use crate::Api;
 use crate::Client;
+use crate::Options;
+use crate::Query;
 use crate::Response;
 use crate::{Deserialize, Serialize};
 
+// ============================================================================
+// Public Enums
+// ============================================================================
+/// How the remaining estimate of an issue changes when work is logged, the
+/// variants that need a value carry it so an incomplete combination can't be
+/// sent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum AdjustEstimate {
+    /// Reduce the remaining estimate by the time spent
+    #[default]
+    Auto,
+
+    /// Leave the remaining estimate as it is
+    Leave,
+
+    /// Set the remaining estimate to a new value e.g. "2d"
+    New(String),
+
+    /// Reduce the remaining estimate by a given amount e.g. "4h"
+    Manual(String),
+}
+
 // ============================================================================
 // Public Structures
 // ============================================================================
+/// Options sent when logging work, see `AdjustEstimate`
+#[derive(Debug, Default)]
+pub struct WorklogOptions {
+    /// How the remaining estimate of the issue is adjusted
+    pub adjust_estimate: AdjustEstimate,
+}
+
+impl WorklogOptions {
+    pub fn new(adjust_estimate: AdjustEstimate) -> Self {
+        WorklogOptions { adjust_estimate }
+    }
+}
+
+impl Options for WorklogOptions {
+    fn to_query(&self) -> Query {
+        let mut h = Query::new();
+
+        match &self.adjust_estimate {
+            AdjustEstimate::Auto => h.insert("adjustEstimate", "auto"),
+            AdjustEstimate::Leave => h.insert("adjustEstimate", "leave"),
+            AdjustEstimate::New(e) => {
+                h.insert("adjustEstimate", "new");
+                h.insert("newEstimate", e.as_str());
+            }
+            AdjustEstimate::Manual(r) => {
+                h.insert("adjustEstimate", "manual");
+                h.insert("reduceBy", r.as_str());
+            }
+        }
+
+        h
+    }
+}
+
+/// The fields required to log work on an issue
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NewWorklog {
+    /// Comment describing the work
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub comment: String,
+
+    /// Date the work started in format: "2020-03-08T16:40:18.010+0000"
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub started: Option<String>,
+
+    /// Time spent in format: "2d 4h"
+    #[serde(rename = "timeSpent", skip_serializing_if = "Option::is_none", default)]
+    pub time_spent: Option<String>,
+
+    /// Time spent in seconds, used when `time_spent` isn't given
+    #[serde(rename = "timeSpentSeconds", skip_serializing_if = "Option::is_none", default)]
+    pub time_spent_seconds: Option<i64>,
+}
+
+impl NewWorklog {
+    pub fn new<T>(time_spent: T) -> Self
+    where
+        T: Into<String>,
+    {
+        NewWorklog {
+            time_spent: Some(time_spent.into()),
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Worklog {
     /// REST API link to the worklog entry
@@ -84,7 +176,25 @@ impl Worklog {
             ids: Vec<i64>
         }
 
-        c.post("api/2/worklog/list", Request {ids: ids.into()})
+        c.post(&Api::V2.endpoint("worklog/list"), Request {ids: ids.into()})
+    }
+
+    /// Logs work on an issue, by default the remaining estimate is reduced
+    /// by the time spent. For more info consult the api docs:
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/8.2.6/#api/2/issue-addWorklog
+    pub fn add<K>(
+        c: &Client,
+        issue_key: K,
+        worklog: NewWorklog,
+        opts: Option<WorklogOptions>,
+    ) -> Response<Worklog>
+    where
+        K: Into<String>,
+    {
+        let query = Client::unpack_options(vec![&opts.unwrap_or_default()]);
+        let endpoint = format!("{}/issue/{}/worklog", Api::V2, encode_segment(&issue_key.into()));
+
+        c.clone().add_query(query).create(&endpoint, worklog)
     }
 }
 
@@ -125,4 +235,19 @@ mod tests {
         assert_eq!(w.issue_id, "10000");
 
     }
+
+    #[test]
+    fn test_options_to_query() {
+        let q = WorklogOptions::new(AdjustEstimate::New("2d".to_string())).to_query();
+
+        assert_eq!(q.get("adjustEstimate"), Some("new"));
+        assert_eq!(q.get("newEstimate"), Some("2d"));
+        assert_eq!(q.get("reduceBy"), None);
+
+        let q = WorklogOptions::new(AdjustEstimate::Manual("4h".to_string())).to_query();
+
+        assert_eq!(q.get("adjustEstimate"), Some("manual"));
+        assert_eq!(q.get("reduceBy"), Some("4h"));
+        assert_eq!(WorklogOptions::default().to_query().get("adjustEstimate"), Some("auto"));
+    }
 }
\ No newline at end of file
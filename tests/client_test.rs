@@ -0,0 +1,762 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{Component, Version};
+use jirac::{Api, Certificate, Client, Identity, Method, Query};
+use jirac::Credentials;
+use jirac::Error;
+use jirac::RetryPolicy;
+use mockito::{mock, Matcher};
+use std::fs;
+use std::net::TcpListener;
+use std::time::Duration;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_etag_cache_returns_cached_body_on_not_modified() {
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+
+    let first = mock("GET", "/rest/api/2/component/10")
+        .match_header("if-none-match", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v1\"")
+        .with_body(result)
+        .expect(1)
+        .create();
+
+    let second = mock("GET", "/rest/api/2/component/10")
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(304)
+        .with_header("x-request-id", "second")
+        .expect(1)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).with_etag_cache();
+
+    let c = Component::from_id(&client, "10").unwrap();
+    assert_eq!(c.data.name, "Component 1");
+
+    let c = Component::from_id(&client, "10").unwrap();
+    assert_eq!(c.data.name, "Component 1");
+    assert_eq!(c.headers.get("x-request-id").unwrap(), "second");
+
+    first.assert();
+    second.assert();
+}
+
+#[test]
+fn test_etag_cache_can_be_bypassed_and_cleared() {
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+
+    let m = mock("GET", "/rest/api/2/component/11")
+        .match_header("if-none-match", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v1\"")
+        .with_body(result)
+        .expect(3)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).with_etag_cache();
+
+    Component::from_id(&client, "11").unwrap();
+    Component::from_id(&client.clone().without_cache(), "11").unwrap();
+    client.clear_etag_cache();
+    Component::from_id(&client, "11").unwrap();
+
+    m.assert();
+}
+
+#[test]
+fn test_builder_with_tls_options() {
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+    let pem = fs::read("tests/assets/ca.pem").expect("Unable to read in PEM file");
+    let p12 = fs::read("tests/assets/identity.p12").expect("Unable to read in PKCS #12 file");
+
+    let _m = mock("GET", "/rest/api/2/component/12")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::builder(url, creds)
+        .add_root_certificate(Certificate::from_pem(&pem).unwrap())
+        .identity(Identity::from_pkcs12_der(&p12, "test").unwrap())
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap();
+
+    let c = Component::from_id(&client, "12").unwrap();
+
+    assert_eq!(c.data.name, "Component 1");
+}
+
+#[test]
+fn test_builder_with_pool_options() {
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+
+    let m = mock("GET", "/rest/api/2/component/13")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .expect(2)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::builder(url, creds)
+        .pool_max_idle_per_host(1)
+        .build()
+        .unwrap();
+
+    Component::from_id(&client, "13").unwrap();
+    Component::from_id(&client, "13").unwrap();
+
+    m.assert();
+}
+
+#[test]
+fn test_max_response_bytes() {
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+    let size = result.len() as u64;
+
+    let _m = mock("GET", "/rest/api/2/component/14")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let small = Client::builder(url, creds.clone())
+        .max_response_bytes(size - 1)
+        .build()
+        .unwrap();
+    let exact = Client::builder(url, creds)
+        .max_response_bytes(size)
+        .build()
+        .unwrap();
+
+    match Component::from_id(&small, "14") {
+        Err(Error::ResponseTooLarge) => (),
+        Err(e) => panic!("expected ResponseTooLarge, got {}", e),
+        Ok(_) => panic!("expected ResponseTooLarge, got a component"),
+    }
+
+    assert_eq!(Component::from_id(&exact, "14").unwrap().data.name, "Component 1");
+}
+
+#[test]
+fn test_retry_idempotent_requests() {
+    let m = mock("GET", "/rest/api/2/component/15")
+        .with_status(503)
+        .expect(3)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).with_retry(RetryPolicy::new(2, Duration::from_millis(1)));
+
+    assert!(Component::from_id(&client, "15").is_err());
+
+    m.assert();
+}
+
+#[test]
+fn test_retry_post_only_when_retry_safe() {
+    let m = mock("POST", "/rest/api/2/version")
+        .with_status(503)
+        .expect(4)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).with_retry(RetryPolicy::new(2, Duration::from_millis(1)));
+
+    assert!(Version::new().create(&client).is_err());
+    assert!(Version::new().create(&client.clone().retry_safe()).is_err());
+
+    m.assert();
+}
+
+#[test]
+fn test_idempotency_key_deduplicates_creates() {
+    let result =
+        fs::read_to_string("tests/assets/v2/version.json").expect("Unable to read in JSON file");
+
+    let m = mock("POST", "/rest/api/2/version")
+        .match_body(Matcher::PartialJsonString(r#"{"name": "dedup"}"#.into()))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .expect(2)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let version = || {
+        let mut v = Version::new();
+        v.name = "dedup".to_string();
+        v
+    };
+
+    let first = version().create(&client.clone().idempotency_key("release-1")).unwrap();
+    let again = version().create(&client.clone().idempotency_key("release-1")).unwrap();
+    version().create(&client.clone().idempotency_key("release-2")).unwrap();
+
+    assert_eq!(first.data.id, again.data.id);
+
+    m.assert();
+}
+
+#[test]
+fn test_idempotency_key_tells_creates_apart_by_body() {
+    let result =
+        fs::read_to_string("tests/assets/v2/version.json").expect("Unable to read in JSON file");
+
+    let first = mock("POST", "/rest/api/2/version")
+        .match_body(Matcher::PartialJsonString(r#"{"name": "body-1"}"#.into()))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(&result)
+        .expect(1)
+        .create();
+    let second = mock("POST", "/rest/api/2/version")
+        .match_body(Matcher::PartialJsonString(r#"{"name": "body-2"}"#.into()))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(&result)
+        .expect(1)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).idempotency_key("release-4");
+
+    for name in &["body-1", "body-2", "body-1"] {
+        let mut v = Version::new();
+        v.name = name.to_string();
+        v.create(&client).unwrap();
+    }
+
+    first.assert();
+    second.assert();
+}
+
+#[test]
+fn test_idempotency_key_only_applies_to_the_same_create() {
+    let result =
+        fs::read_to_string("tests/assets/v2/version.json").expect("Unable to read in JSON file");
+    let component =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+
+    let create = mock("POST", "/rest/api/2/version")
+        .match_body(Matcher::PartialJsonString(r#"{"name": "keyed"}"#.into()))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .expect(1)
+        .create();
+    let get = mock("GET", "/rest/api/2/component/10000")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(component)
+        .expect(1)
+        .create();
+    let other = mock("POST", "/rest/api/2/component")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id": "10001", "name": "other"}"#)
+        .expect(1)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).idempotency_key("release-3");
+
+    let mut v = Version::new();
+    v.name = "keyed".to_string();
+    v.create(&client).unwrap();
+
+    let c = Component::from_id(&client, "10000").unwrap().data;
+    let created: serde_json::Value = client
+        .create(&Api::V2.endpoint("component"), serde_json::json!({"name": "other"}))
+        .unwrap()
+        .data;
+
+    assert_eq!(c.id, "10000");
+    assert_eq!(created["id"], "10001");
+
+    create.assert();
+    get.assert();
+    other.assert();
+}
+
+#[test]
+fn test_session_login_and_logout() {
+    let session =
+        fs::read_to_string("tests/assets/v2/session.json").expect("Unable to read in JSON file");
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+
+    let login = mock("POST", "/rest/auth/1/session")
+        .match_header("cookie", Matcher::Missing)
+        .match_header("authorization", Matcher::Missing)
+        .match_body(Matcher::Json(serde_json::json!({"username": "fred", "password": "secret"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(session)
+        .create();
+
+    let get = mock("GET", "/rest/api/2/component/16")
+        .match_header("cookie", "JSESSIONID=6E3487971234567896704A9EB4AE501F")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let logout = mock("DELETE", "/rest/auth/1/session")
+        .match_header("cookie", "JSESSIONID=6E3487971234567896704A9EB4AE501F")
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let client = Client::login(url, "fred", "secret").unwrap();
+
+    Component::from_id(&client, "16").unwrap();
+    client.logout().unwrap();
+
+    login.assert();
+    get.assert();
+    logout.assert();
+}
+
+#[test]
+fn test_session_login_rejected() {
+    let login = mock("POST", "/rest/auth/1/session")
+        .match_body(Matcher::Json(serde_json::json!({"username": "fred", "password": "wrong"})))
+        .with_status(401)
+        .create();
+
+    let url = &mockito::server_url();
+
+    match Client::login(url, "fred", "wrong") {
+        Err(Error::Unauthorized) => (),
+        Err(e) => panic!("expected Unauthorized, got {}", e),
+        Ok(_) => panic!("expected Unauthorized, got a client"),
+    }
+
+    login.assert();
+}
+
+#[test]
+fn test_session_expired() {
+    let _m = mock("GET", "/rest/api/2/component/17")
+        .with_status(401)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_session("JSESSIONID", "stale").unwrap();
+    let client = Client::new(url, creds);
+
+    match Component::from_id(&client, "17") {
+        Err(Error::SessionExpired) => (),
+        Err(e) => panic!("expected SessionExpired, got {}", e),
+        Ok(_) => panic!("expected SessionExpired, got a component"),
+    }
+}
+
+#[test]
+fn test_connect_error_is_categorized() {
+    // Bind then drop a listener so the port is known to refuse connections
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+
+    let url = format!("http://127.0.0.1:{}", port);
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    match Component::from_id(&client, "17") {
+        Err(e @ Error::Connect(_)) => {
+            assert!(e.is_retryable());
+            assert_eq!(e.status(), None);
+        }
+        Err(e) => panic!("expected Connect, got {}", e),
+        Ok(_) => panic!("expected Connect, got a component"),
+    }
+}
+
+#[test]
+fn test_error_is_retryable() {
+    let _unavailable = mock("GET", "/rest/api/2/component/503")
+        .with_status(503)
+        .with_body("Service Unavailable")
+        .create();
+    let _invalid = mock("GET", "/rest/api/2/component/400")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorMessages": ["Bad id"]}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    match Component::from_id(&client, "503") {
+        Err(e) => {
+            assert!(e.is_retryable());
+            assert_eq!(e.status(), Some(503));
+        }
+        Ok(_) => panic!("expected a fault"),
+    }
+    match Component::from_id(&client, "400") {
+        Err(e) => {
+            assert!(!e.is_retryable());
+            assert_eq!(e.status(), Some(400));
+        }
+        Ok(_) => panic!("expected a fault"),
+    }
+}
+
+#[test]
+fn test_timeout_error_is_categorized() {
+    // Accepts the connection but never answers
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let mut client = Client::new(url, creds);
+    client.client = reqwest::Client::builder()
+        .timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+
+    match Component::from_id(&client, "17") {
+        Err(Error::Timeout(_)) => (),
+        Err(e) => panic!("expected Timeout, got {}", e),
+        Ok(_) => panic!("expected Timeout, got a component"),
+    }
+}
+
+#[test]
+fn test_patch() {
+    let m = mock("PATCH", "/rest/api/2/thing/1")
+        .match_body(Matcher::Json(serde_json::json!({"name": "new"})))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let r = client.patch::<_, ()>(&Api::V2.endpoint("thing/1"), serde_json::json!({"name": "new"}));
+
+    assert!(r.is_ok());
+    m.assert();
+}
+
+#[test]
+fn test_method_override() {
+    let m = mock("POST", "/rest/api/2/thing/2")
+        .match_header("x-http-method-override", "DELETE")
+        .with_status(204)
+        .create();
+    let get = mock("GET", "/rest/api/2/thing/2")
+        .match_header("x-http-method-override", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::builder(url.as_str(), creds).method_override().build().unwrap();
+
+    assert!(client.delete::<()>(&Api::V2.endpoint("thing/2")).is_ok());
+    assert!(client.get::<serde_json::Value>(&Api::V2.endpoint("thing/2")).is_ok());
+    m.assert();
+    get.assert();
+}
+
+#[test]
+fn test_connect_jwt() {
+    let m = mock("GET", "/rest/api/2/thing/3")
+        .match_header("authorization", Matcher::Regex(r"^JWT [\w-]+\.[\w-]+\.[\w-]+$".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_connect_jwt("my-app", "secret", None).unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(client.get::<serde_json::Value>(&Api::V2.endpoint("thing/3")).is_ok());
+    m.assert();
+}
+
+#[test]
+fn test_record_and_replay_fixtures() {
+    let dir = std::env::temp_dir().join(format!("jirac-fixtures-{}", std::process::id()));
+    let m = mock("GET", "/rest/api/2/thing/4")
+        .match_query(Matcher::UrlEncoded("expand".into(), "names".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("set-cookie", "JSESSIONID=6E3487971234; Path=/")
+        .with_body(r#"{"name": "thing"}"#)
+        .expect(1)
+        .create();
+
+    let creds = Credentials::new_basic("test", "secret").unwrap();
+    let recorder = Client::builder(mockito::server_url(), creds.clone())
+        .record_fixtures(&dir)
+        .build()
+        .unwrap();
+    let mut query = Query::new();
+
+    query.insert("expand", "names");
+
+    let recorded = recorder
+        .clone()
+        .add_query(query.clone())
+        .get::<serde_json::Value>(&Api::V2.endpoint("thing/4"))
+        .unwrap();
+
+    let fixture = fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+    let contents = fs::read_to_string(&fixture).unwrap();
+
+    assert!(!contents.contains("6E3487971234"));
+    assert!(!contents.contains("secret"));
+
+    // Replaying never reaches the host
+    let replayer = Client::builder("http://127.0.0.1:1", creds)
+        .replay_fixtures(&dir)
+        .build()
+        .unwrap();
+    let replayed = replayer
+        .clone()
+        .add_query(query)
+        .get::<serde_json::Value>(&Api::V2.endpoint("thing/4"))
+        .unwrap();
+
+    assert_eq!(replayed.data, recorded.data);
+    assert!(matches!(
+        replayer.get::<serde_json::Value>(&Api::V2.endpoint("thing/5")),
+        Err(Error::FixtureNotFound(_))
+    ));
+    m.assert();
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_record_fixtures_scrubs_sent_request_and_keeps_cached_bodies() {
+    let dir = std::env::temp_dir().join(format!("jirac-fixtures-etag-{}", std::process::id()));
+    let revalidated = mock("GET", "/rest/api/2/thing/6")
+        .match_query(Matcher::Any)
+        .match_header("if-none-match", "\"1\"")
+        .with_status(304)
+        .expect(0)
+        .create();
+    let m = mock("GET", "/rest/api/2/thing/6")
+        .match_query(Matcher::UrlEncoded("access_token".into(), "xyz123".into()))
+        .match_header("if-none-match", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"1\"")
+        .with_body(r#"{"name": "thing"}"#)
+        .expect(2)
+        .create();
+
+    let creds = Credentials::new_basic("test", "secret").unwrap();
+    let recorder = Client::builder(mockito::server_url(), creds.clone())
+        .record_fixtures(&dir)
+        .build()
+        .unwrap()
+        .with_etag_cache();
+    let mut query = Query::new();
+
+    query.insert("access_token", "xyz123");
+
+    let recorder = recorder.add_query(query.clone());
+
+    recorder.get::<serde_json::Value>(&Api::V2.endpoint("thing/6")).unwrap();
+    recorder.get::<serde_json::Value>(&Api::V2.endpoint("thing/6")).unwrap();
+
+    let fixture = fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+    let contents: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&fixture).unwrap()).unwrap();
+    let headers = contents["request"]["headers"].as_array().unwrap();
+
+    assert!(headers.contains(&serde_json::json!(["authorization", "<redacted>"])));
+    assert!(headers.contains(&serde_json::json!(["content-type", "application/json"])));
+    assert_eq!(contents["request"]["url"], "rest/api/2/thing/6?access_token=<redacted>");
+    assert_eq!(contents["response"]["status"], 200);
+    assert!(!contents.to_string().contains("xyz123"));
+
+    let replayer = Client::builder("http://127.0.0.1:1", creds)
+        .replay_fixtures(&dir)
+        .build()
+        .unwrap()
+        .add_query(query);
+    let replayed = replayer.get::<serde_json::Value>(&Api::V2.endpoint("thing/6")).unwrap();
+
+    assert_eq!(replayed.data, serde_json::json!({"name": "thing"}));
+    m.assert();
+    revalidated.assert();
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_request_raw() {
+    let m = mock("POST", "/rest/api/2/issue/TEST-1/properties/flag")
+        .match_query(Matcher::UrlEncoded("notifyUsers".into(), "false".into()))
+        .match_body(Matcher::Json(serde_json::json!({"flagged": true})))
+        .match_header("authorization", Matcher::Regex("^Basic ".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"key": "flag", "value": {"flagged": true}}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let mut query = Query::new();
+    query.insert("notifyUsers", "false");
+
+    let r = client
+        .request_raw(
+            Method::POST,
+            &Api::V2.endpoint("issue/TEST-1/properties/flag"),
+            query,
+            Some(serde_json::json!({"flagged": true})),
+        )
+        .unwrap();
+
+    assert_eq!(r.data["value"]["flagged"], true);
+    m.assert();
+}
+
+#[test]
+fn test_ttl_cache_memoizes_gets() {
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+
+    let m = mock("GET", "/rest/api/2/component/30")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .expect(2)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).with_cache(Duration::from_secs(60));
+
+    // Only the first request and the bypassed one reach the server
+    for _ in 0..3 {
+        assert_eq!(Component::from_id(&client, "30").unwrap().data.name, "Component 1");
+    }
+    Component::from_id(&client.clone().without_cache(), "30").unwrap();
+
+    m.assert();
+}
+
+#[test]
+fn test_ttl_cache_skips_errors() {
+    let m = mock("GET", "/rest/api/2/component/31")
+        .with_status(404)
+        .expect(2)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).with_cache(Duration::from_secs(60));
+
+    assert!(Component::from_id(&client, "31").is_err());
+    assert!(Component::from_id(&client, "31").is_err());
+
+    m.assert();
+}
+
+#[test]
+fn test_check_reachable_and_authenticated() {
+    let info = fs::read_to_string("tests/assets/v2/server_info.json")
+        .expect("Unable to read in JSON file");
+    let user =
+        fs::read_to_string("tests/assets/v2/user.json").expect("Unable to read in JSON file");
+
+    let _info = mock("GET", "/rest/api/2/serverInfo")
+        .match_header("authorization", "Basic aGVhbHRoeTp0ZXN0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(info)
+        .create();
+
+    let _user = mock("GET", "/rest/api/2/myself")
+        .match_header("authorization", "Basic aGVhbHRoeTp0ZXN0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(user)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("healthy", "test").unwrap();
+    let report = Client::new(url, creds).check().unwrap();
+
+    assert!(report.is_authenticated());
+    assert_eq!(report.server.unwrap().version, "8.2.6");
+    assert_eq!(report.user.unwrap().name, "fred");
+}
+
+#[test]
+fn test_check_reachable_but_unauthorized() {
+    let _info = mock("GET", "/rest/api/2/serverInfo")
+        .match_header("authorization", "Basic d3Jvbmc6dGVzdA==")
+        .with_status(401)
+        .create();
+
+    let _user = mock("GET", "/rest/api/2/myself")
+        .match_header("authorization", "Basic d3Jvbmc6dGVzdA==")
+        .with_status(401)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("wrong", "test").unwrap();
+    let report = Client::new(url, creds).check().unwrap();
+
+    assert!(!report.is_authenticated());
+    assert!(report.server.is_none());
+}
+
+#[test]
+fn test_check_unreachable() {
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+
+    let url = format!("http://127.0.0.1:{}", port);
+    let creds = Credentials::new_basic("test", "test").unwrap();
+
+    assert!(matches!(Client::new(url, creds).check(), Err(Error::Connect(_))));
+}
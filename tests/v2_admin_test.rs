@@ -0,0 +1,149 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{FieldConfiguration, IssueTypeScheme, Screen};
+use jirac::Client;
+use jirac::Credentials;
+use jirac::Error;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_issue_type_schemes_for_projects() {
+    let result = fs::read_to_string("tests/assets/v2/issue_type_scheme_projects.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/issuetypescheme/project")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("projectId".into(), "10000".into()),
+            Matcher::UrlEncoded("projectId".into(), "10001".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let schemes = IssueTypeScheme::for_projects(&client, &["10000", "10001"], None)
+        .unwrap()
+        .data;
+
+    assert_eq!(schemes.values[0].issue_type_scheme.id, "10000");
+    assert_eq!(schemes.values[0].project_ids.len(), 2);
+}
+
+#[test]
+fn test_field_configurations() {
+    let result = fs::read_to_string("tests/assets/v2/field_configurations.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/fieldconfiguration")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let configs = FieldConfiguration::all(&client, None).unwrap().data;
+
+    assert_eq!(configs.values.len(), 2);
+}
+
+#[test]
+fn test_admin_reads_forbidden() {
+    let _m = mock("GET", "/rest/api/2/issuetypescheme")
+        .match_query(Matcher::Any)
+        .with_status(403)
+        .with_header("content-type", "text/html")
+        .with_body("<html>Forbidden</html>")
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(matches!(IssueTypeScheme::all(&client, None), Err(Error::Forbidden)));
+}
+
+#[test]
+fn test_unexpected_error_body_is_kept() {
+    let _m = mock("GET", "/rest/api/2/fieldconfigurationscheme/project")
+        .match_query(Matcher::Any)
+        .with_status(500)
+        .with_body("<html>Internal Server Error</html>")
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    match FieldConfiguration::schemes_for_projects(&client, &["10000"], None) {
+        Err(Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 500);
+            assert_eq!(errors.error_messages, vec!["<html>Internal Server Error</html>"]);
+        }
+        Err(e) => panic!("expected a fault, got {:?}", e),
+        Ok(_) => panic!("expected a fault"),
+    }
+}
+
+#[test]
+fn test_screen_layout() {
+    let _tabs = mock("GET", "/rest/api/2/screens/1/tabs")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"[{"id": 10000, "name": "Field Tab"}, {"id": 10001, "name": "Dates"}]"#)
+        .create();
+    let _first = mock("GET", "/rest/api/2/screens/1/tabs/10000/fields")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"[{"id": "summary", "name": "Summary"}, {"id": "issuetype", "name": "Type"}]"#)
+        .create();
+    let _second = mock("GET", "/rest/api/2/screens/1/tabs/10001/fields")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"[{"id": "duedate", "name": "Due date"}]"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let layout = Screen::layout(&client, 1).unwrap();
+
+    assert_eq!(layout.len(), 2);
+    assert_eq!(layout[0].tab.name, "Field Tab");
+    assert!(layout[0].contains("summary"));
+    assert_eq!(layout[1].fields[0].id, "duedate");
+}
+
+#[test]
+fn test_screens_forbidden() {
+    let _m = mock("GET", "/rest/api/2/screens")
+        .match_query(Matcher::Any)
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorMessages": ["Only Jira administrators can access screens."]}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(matches!(Screen::all(&client, None), Err(Error::Forbidden)));
+}
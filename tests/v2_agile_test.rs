@@ -0,0 +1,95 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{Board, Sprint};
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_board_all() {
+    let result =
+        fs::read_to_string("tests/assets/v2/boards.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/agile/1.0/board")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::UrlEncoded("startAt".into(), "0".into()))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let b = Board::all(&client, None).unwrap();
+
+    assert_eq!(b.data.values.len(), 2);
+}
+
+#[test]
+fn test_sprint_for_board() {
+    let result =
+        fs::read_to_string("tests/assets/v2/sprints.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/agile/1.0/board/5/sprint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::Any)
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let s = Sprint::for_board(&client, "5", None).unwrap();
+
+    assert_eq!(s.data.values[1].name, "sprint 2");
+}
+
+#[test]
+fn test_sprint_issues() {
+    let result =
+        fs::read_to_string("tests/assets/v2/search.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/agile/1.0/sprint/37/issue")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::Any)
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let s = Sprint::issues(&client, "37", None).unwrap();
+
+    assert!(!s.data.issues.is_empty());
+}
+
+#[test]
+fn test_sprint_move_issues() {
+    let m = mock("POST", "/rest/agile/1.0/sprint/37/issue")
+        .match_body(Matcher::Json(serde_json::json!({"issues": ["PR-1", "PR-2"]})))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    Sprint::move_issues(&client, "37", vec!["PR-1".into(), "PR-2".into()]).unwrap();
+
+    m.assert();
+}
@@ -7,10 +7,11 @@ extern crate mockito;
 // ============================================================================
 // Use
 // ============================================================================
-use jirac::v2::{ApplicationRole, ApplicationRoleOptions};
+use jirac::v2::ApplicationRole;
 use jirac::Client;
 use jirac::Credentials;
 use jirac::Error;
+use jirac::Precondition;
 use mockito::mock;
 use std::fs;
 
@@ -83,9 +84,10 @@ fn test_put_with_key_and_options() {
         .expect("Unable to read in JSON file");
 
     let a: ApplicationRole = serde_json::from_str(&result).unwrap();
-    let o = ApplicationRoleOptions::new(String::from("test"));
+    let o = Precondition::if_match("test");
 
     let _m = mock("PUT", "/rest/api/2/applicationrole/jira-software")
+        .match_header("if-match", "test")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_header("If-Match", "test")
@@ -0,0 +1,84 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::Attachment;
+use jirac::Client;
+use jirac::Credentials;
+use jirac::Error;
+use mockito::mock;
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_download() {
+    let url = &mockito::server_url();
+    let result = fs::read_to_string("tests/assets/v2/attachment.json")
+        .expect("Unable to read in JSON file")
+        .replace("http://www.example.com/jira", url);
+    // Not valid UTF-8 so decoding it as text or JSON would fail
+    let image = vec![0xff, 0xd8, 0xff, 0xe0, 0x00, 0x10];
+
+    let _m1 = mock("GET", "/rest/api/2/attachment/10000")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+    let _m2 = mock("GET", "/attachments/10000")
+        .match_header("authorization", mockito::Matcher::Regex("^Basic ".into()))
+        .with_status(200)
+        .with_header("content-type", "image/jpeg")
+        .with_body(&image)
+        .create();
+
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let attachment = Attachment::from_id(&client, "10000").unwrap().data;
+    let content = attachment.download(&client).unwrap();
+
+    assert_eq!(content.data, image);
+    assert_eq!(content.headers["content-type"], "image/jpeg");
+}
+
+// ============================================================================
+// Tests (Sad Path)
+// ============================================================================
+#[test]
+fn test_download_not_found() {
+    let url = &mockito::server_url();
+
+    let _m = mock("GET", "/attachments/404")
+        .with_status(404)
+        .create();
+
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let result = client.download(&format!("{}/attachments/404", url));
+
+    assert!(matches!(result, Err(Error::NotFound)));
+}
+
+#[test]
+fn test_download_foreign_url() {
+    let result = fs::read_to_string("tests/assets/v2/attachment.json")
+        .expect("Unable to read in JSON file");
+    let attachment: Attachment = serde_json::from_str(&result).unwrap();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    match attachment.download(&client) {
+        Err(Error::ForeignUrl(u)) => assert_eq!(u, "http://www.example.com/jira/attachments/10000"),
+        Err(e) => panic!("expected ForeignUrl, got {:?}", e),
+        Ok(_) => panic!("expected ForeignUrl"),
+    }
+}
@@ -0,0 +1,112 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{AvatarCrop, Project, User};
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_user_set_avatar_uses_suggested_crop() {
+    let temporary = fs::read_to_string("tests/assets/v2/avatar_temporary.json")
+        .expect("Unable to read in JSON file");
+    let created = fs::read_to_string("tests/assets/v2/avatar_created.json")
+        .expect("Unable to read in JSON file");
+
+    let upload = mock("POST", "/rest/api/2/user/avatar/temporary")
+        .match_header("x-atlassian-token", "no-check")
+        .match_header("content-type", "image/png")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("username".into(), "fred".into()),
+            Matcher::UrlEncoded("size".into(), "4".into()),
+        ]))
+        .match_body("png!")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(temporary)
+        .create();
+
+    let confirm = mock("POST", "/rest/api/2/user/avatar")
+        .match_query(Matcher::UrlEncoded("username".into(), "fred".into()))
+        .match_body(Matcher::Json(serde_json::json!({
+            "cropperOffsetX": 50,
+            "cropperOffsetY": 50,
+            "cropperWidth": 120,
+            "needsCropping": false
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(created)
+        .create();
+
+    let select = mock("PUT", "/rest/api/2/user/avatar")
+        .match_query(Matcher::UrlEncoded("username".into(), "fred".into()))
+        .match_body(Matcher::Json(serde_json::json!({"id": "1010"})))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let id = User::set_avatar(&client, "fred", b"png!".to_vec(), "image/png", None).unwrap();
+
+    upload.assert();
+    confirm.assert();
+    select.assert();
+    assert_eq!(id.data, "1010");
+}
+
+#[test]
+fn test_project_set_avatar_with_crop() {
+    let temporary = fs::read_to_string("tests/assets/v2/avatar_temporary.json")
+        .expect("Unable to read in JSON file");
+    let created = fs::read_to_string("tests/assets/v2/avatar_created.json")
+        .expect("Unable to read in JSON file");
+
+    let _upload = mock("POST", "/rest/api/2/project/EX/avatar/temporary")
+        .match_query(Matcher::Any)
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(temporary)
+        .create();
+
+    let confirm = mock("POST", "/rest/api/2/project/EX/avatar")
+        .match_body(Matcher::Json(serde_json::json!({
+            "cropperOffsetX": 0,
+            "cropperOffsetY": 0,
+            "cropperWidth": 48,
+            "needsCropping": false
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(created)
+        .create();
+
+    let _select = mock("PUT", "/rest/api/2/project/EX/avatar")
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let crop = AvatarCrop {
+        x: 0,
+        y: 0,
+        width: 48,
+    };
+    let id = Project::set_avatar(&client, "EX", vec![1], "image/jpeg", Some(crop)).unwrap();
+
+    confirm.assert();
+    assert_eq!(id.data, "1010");
+}
@@ -0,0 +1,61 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{AdfDocument, Comment, CommentBody};
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_add_wiki_comment() {
+    let result =
+        fs::read_to_string("tests/assets/v2/comment.json").expect("Unable to read in JSON file");
+
+    let m = mock("POST", "/rest/api/2/issue/TEST-1/comment")
+        .match_body(Matcher::Json(serde_json::json!({"body": "Looks *good* to me"})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let c = Comment::add(&client, "TEST-1", "Looks *good* to me".into()).unwrap();
+
+    m.assert();
+    assert_eq!(c.data.body, CommentBody::Wiki("Looks *good* to me".to_string()));
+}
+
+#[test]
+fn test_add_adf_comment() {
+    let result = fs::read_to_string("tests/assets/v2/comment_adf.json")
+        .expect("Unable to read in JSON file");
+    let doc = AdfDocument::new().paragraph("Looks good to me");
+
+    let m = mock("POST", "/rest/api/3/issue/TEST-1/comment")
+        .match_body(Matcher::Json(serde_json::json!({"body": doc.to_value()})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let c = Comment::add(&client, "TEST-1", doc.into()).unwrap();
+
+    m.assert();
+    assert_eq!(c.data.id, "10000");
+}
@@ -9,7 +9,7 @@ extern crate jirac;
 use jirac::v2::Component;
 use jirac::Client;
 use jirac::Credentials;
-use mockito::mock;
+use mockito::{mock, Matcher};
 use std::fs;
 
 // ============================================================================
@@ -34,3 +34,59 @@ fn test_get_from_id() {
 
     assert_eq!(c.data.name, "Component 1");
 }
+
+#[test]
+fn test_get_from_id_encodes_segment() {
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/component/a%2Fb%20c")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let c = Component::from_id(&client, "a/b c").unwrap();
+
+    assert_eq!(c.data.name, "Component 1");
+}
+
+#[test]
+fn test_delete_self() {
+    let result =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+    let component: Component = serde_json::from_str(&result).unwrap();
+    let endpoint = format!("/rest/api/2/component/{}", component.id);
+
+    let m = mock("DELETE", endpoint.as_str())
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    component.delete_self(&client).unwrap();
+
+    m.assert();
+}
+
+#[test]
+fn test_delete_and_replace() {
+    let m = mock("DELETE", "/rest/api/2/component/10001")
+        .match_query(Matcher::UrlEncoded("moveIssuesTo".into(), "10002".into()))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    Component::delete_and_replace(&client, "10001", "10002").unwrap();
+
+    m.assert();
+}
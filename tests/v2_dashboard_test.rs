@@ -0,0 +1,61 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{Dashboard, Pagination};
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_all() {
+    let result =
+        fs::read_to_string("tests/assets/v2/dashboards.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/dashboard")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("startAt".into(), "10".into()),
+            Matcher::UrlEncoded("maxResults".into(), "10".into()),
+        ]))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let d = Dashboard::all(&client, Some(Pagination::new(10, 10))).unwrap();
+
+    assert_eq!(d.data.dashboards.len(), 2);
+    assert_eq!(d.data.pagination.total, 143);
+}
+
+#[test]
+fn test_get_from_id() {
+    let result =
+        fs::read_to_string("tests/assets/v2/dashboard.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/dashboard/10000")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let d = Dashboard::from_id(&client, "10000").unwrap();
+
+    assert_eq!(d.data.name, "System Dashboard");
+}
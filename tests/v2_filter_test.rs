@@ -0,0 +1,88 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{Filter, NewFilter};
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_get_from_id() {
+    let result =
+        fs::read_to_string("tests/assets/v2/filter.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/filter/10000")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let f = Filter::from_id(&client, "10000").unwrap();
+
+    assert_eq!(f.data.name, "All Open Bugs");
+}
+
+#[test]
+fn test_create() {
+    let result =
+        fs::read_to_string("tests/assets/v2/filter.json").expect("Unable to read in JSON file");
+
+    let _m = mock("POST", "/rest/api/2/filter")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"name": "All Open Bugs", "jql": "type = Bug and resolution is empty"}"#
+                .to_string(),
+        ))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let n = NewFilter::new("All Open Bugs", "type = Bug and resolution is empty");
+    let f = Filter::create(&client, n).unwrap();
+
+    assert_eq!(f.data.id, "10000");
+}
+
+#[test]
+fn test_run() {
+    let filter =
+        fs::read_to_string("tests/assets/v2/filter.json").expect("Unable to read in JSON file");
+    let result =
+        fs::read_to_string("tests/assets/v2/search.json").expect("Unable to read in JSON file");
+
+    let _m = mock("POST", "/rest/api/2/search")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_body(Matcher::JsonString(
+            r#"{"jql": "type = Bug and resolution is empty", "startAt": 0, "maxResults": 50}"#
+                .to_string(),
+        ))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let f: Filter = serde_json::from_str(&filter).unwrap();
+    let r = Filter::run(&client, &f, None).unwrap();
+
+    assert_eq!(r.data.issues.len(), 1);
+}
@@ -40,3 +40,67 @@ fn test_get_member() {
 
     assert_eq!(c.data.users.len(), 2);
 }
+
+#[test]
+fn test_create() {
+    let m = mock("POST", "/rest/api/2/group")
+        .match_body(Matcher::Json(serde_json::json!({"name": "release-managers"})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"name": "release-managers", "self": "http://www.example.com/jira/rest/api/2/group?groupname=release-managers"}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let g = Group::create(&client, "release-managers").unwrap();
+
+    assert_eq!(g.data.name, "release-managers");
+    m.assert();
+}
+
+#[test]
+fn test_delete_with_swap_group() {
+    let m = mock("DELETE", "/rest/api/2/group")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("groupname".into(), "old-team".into()),
+            Matcher::UrlEncoded("swapGroup".into(), "new-team".into()),
+        ]))
+        .with_status(200)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    Group::delete(&client, "old-team", Some("new-team".to_string())).unwrap();
+
+    m.assert();
+}
+
+#[test]
+fn test_find() {
+    let result = fs::read_to_string("tests/assets/v2/group_picker.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/groups/picker")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("query".into(), "admin".into()),
+            Matcher::UrlEncoded("maxResults".into(), "10".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let found = Group::find(&client, "admin", Some(10)).unwrap().data;
+
+    assert_eq!(found.total, 2);
+    assert_eq!(found.groups[0].name, "jira-administrators");
+    assert!(found.groups[1].group_id.is_none());
+}
@@ -0,0 +1,539 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{BulkTransitionOptions, Issue, IssueEdit, IssueTransition, NewIssue};
+use jirac::v2::{Notification, Resolution, TransitionRef};
+use jirac::Client;
+use jirac::Credentials;
+use jirac::Error;
+use jirac::Precondition;
+use mockito::{mock, Matcher};
+use std::collections::BTreeMap;
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_votes() {
+    let result =
+        fs::read_to_string("tests/assets/v2/vote.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/issue/MKY-1/votes")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let v = Issue::votes(&client, "MKY-1").unwrap();
+
+    assert_eq!(v.data.votes, 24);
+    assert!(v.data.has_voted);
+}
+
+#[test]
+fn test_vote_and_unvote() {
+    let _p = mock("POST", "/rest/api/2/issue/MKY-2/votes")
+        .with_status(204)
+        .create();
+    let _d = mock("DELETE", "/rest/api/2/issue/MKY-2/votes")
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(Issue::vote(&client, "MKY-2").is_ok());
+    assert!(Issue::unvote(&client, "MKY-2").is_ok());
+}
+
+#[test]
+fn test_add_labels() {
+    let _m = mock("PUT", "/rest/api/2/issue/MKY-3")
+        .with_status(204)
+        .match_body(Matcher::JsonString(
+            r#"{"update": {"labels": [{"add": "backend"}, {"add": "urgent"}]}}"#.to_string(),
+        ))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let labels = vec!["backend".to_string(), "urgent".to_string()];
+
+    assert!(Issue::add_labels(&client, "MKY-3", labels).is_ok());
+}
+
+#[test]
+fn test_edit() {
+    let _m = mock("PUT", "/rest/api/2/issue/MKY-4")
+        .with_status(204)
+        .match_body(Matcher::JsonString(
+            r#"{"update": {"components": [{"add": {"name": "Backend"}}], "summary": [{"set": "Hello"}]}}"#
+                .to_string(),
+        ))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let ops = IssueEdit::new().add_component("Backend").set_summary("Hello");
+
+    assert!(Issue::edit(&client, "MKY-4", ops).is_ok());
+}
+
+#[test]
+fn test_assign_without_notifications() {
+    let m = mock("PUT", "/rest/api/2/issue/MKY-6")
+        .match_query(Matcher::UrlEncoded("notifyUsers".into(), "false".into()))
+        .match_body(Matcher::Json(serde_json::json!({
+            "update": {"assignee": [{"set": {"name": "fred"}}]}
+        })))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(Issue::assign(&client, "MKY-6", Some("fred"), false).is_ok());
+    m.assert();
+}
+
+#[test]
+fn test_transition_without_notifications() {
+    let m = mock("POST", "/rest/api/2/issue/MKY-7/transitions")
+        .match_query(Matcher::UrlEncoded("notifyUsers".into(), "false".into()))
+        .match_body(Matcher::Json(serde_json::json!({"transition": {"id": "11"}})))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let transition = IssueTransition::new("11").notify_users(false);
+
+    assert!(Issue::transition(&client, "MKY-7", transition).is_ok());
+    m.assert();
+}
+
+#[test]
+fn test_edit_if_precondition_failed() {
+    let m = mock("PUT", "/rest/api/2/issue/MKY-5")
+        .match_header("if-match", "abc")
+        .with_status(412)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let ops = IssueEdit::new().set_summary("Hello");
+    let result = Issue::edit_if(&client, "MKY-5", ops, Precondition::if_match("abc"));
+
+    assert!(matches!(result, Err(Error::PreconditionFailed)));
+    m.assert();
+}
+
+#[test]
+fn test_delete_self() {
+    let result =
+        fs::read_to_string("tests/assets/v2/issue.json").expect("Unable to read in JSON file");
+    let issue: Issue = serde_json::from_str(&result).unwrap();
+    let endpoint = format!("/rest/api/2/issue/{}", issue.key);
+
+    let m = mock("DELETE", endpoint.as_str())
+        .match_query(Matcher::UrlEncoded("deleteSubtasks".into(), "true".into()))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    issue.delete_self(&client, true).unwrap();
+
+    m.assert();
+}
+
+#[test]
+fn test_notify() {
+    let m = mock("POST", "/rest/api/2/issue/TEST-1/notify")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"subject": "Heads up", "to": {"assignee": true, "groups": [{"name": "ops"}]}}"#.into(),
+        ))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let mut n = Notification::new("Heads up", "Deploying TEST-1");
+    n.to.assignee = true;
+    n.to.groups = vec!["ops".to_string()];
+
+    Issue::notify(&client, "TEST-1", &n).unwrap();
+
+    m.assert();
+}
+
+#[test]
+fn test_transition_with_resolution() {
+    let transitions =
+        fs::read_to_string("tests/assets/v2/transitions.json").expect("Unable to read in JSON file");
+    let resolutions =
+        fs::read_to_string("tests/assets/v2/resolutions.json").expect("Unable to read in JSON file");
+
+    let _m1 = mock("GET", "/rest/api/2/issue/TEST-1/transitions")
+        .match_query(Matcher::UrlEncoded("expand".into(), "transitions.fields".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions)
+        .create();
+
+    let _m2 = mock("GET", "/rest/api/2/resolution")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(resolutions)
+        .create();
+
+    let m3 = mock("POST", "/rest/api/2/issue/TEST-1/transitions")
+        .match_body(Matcher::Json(serde_json::json!({
+            "transition": {"id": "31"},
+            "fields": {"resolution": {"id": "10001"}}
+        })))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let done = Issue::transitions(&client, "TEST-1")
+        .unwrap()
+        .data
+        .into_iter()
+        .find(|t| t.name == "Done")
+        .unwrap();
+    let wont_do = Resolution::all(&client)
+        .unwrap()
+        .data
+        .into_iter()
+        .find(|r| r.name == "Won't Do")
+        .unwrap();
+
+    Issue::transition(&client, "TEST-1", IssueTransition::new(done.id).set_resolution(&wont_do))
+        .unwrap();
+
+    m3.assert();
+}
+
+#[test]
+fn test_transition_by_name() {
+    let transitions =
+        fs::read_to_string("tests/assets/v2/transitions.json").expect("Unable to read in JSON file");
+
+    let _m1 = mock("GET", "/rest/api/2/issue/TEST-2/transitions")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions)
+        .create();
+    let m2 = mock("POST", "/rest/api/2/issue/TEST-2/transitions")
+        .match_body(Matcher::Json(serde_json::json!({
+            "transition": {"id": "31"},
+            "fields": {"resolution": {"name": "Done"}}
+        })))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let mut fields = BTreeMap::new();
+    fields.insert("resolution".to_string(), serde_json::json!({"name": "Done"}));
+
+    assert!(Issue::transition_by_name(&client, "TEST-2", "done", fields).is_ok());
+    m2.assert();
+}
+
+#[test]
+fn test_create_subtask() {
+    let _parent = mock_subtask_parent("TEST-30");
+    let m = mock("POST", "/rest/api/2/issue")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"fields": {"parent": {"key": "TEST-1"}, "project": {"id": "10000"}}}"#.into(),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id": "10200", "key": "TEST-31", "self": ""}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    // The project is taken from the parent
+    let issue = NewIssue::default()
+        .set("issuetype", serde_json::json!({"name": "sub-task"}))
+        .set("summary", "Write tests");
+
+    assert_eq!(Issue::create_subtask(&client, "TEST-30", issue).unwrap().data.key, "TEST-31");
+    m.assert();
+}
+
+#[test]
+fn test_transition_bulk() {
+    let transitions =
+        fs::read_to_string("tests/assets/v2/transitions.json").expect("Unable to read in JSON file");
+    let mut done: serde_json::Value = serde_json::from_str(&transitions).unwrap();
+
+    // The second workflow has its own id for Done and the third doesn't
+    // allow it from the current status
+    done["transitions"][1]["id"] = "71".into();
+
+    let _transitions: Vec<mockito::Mock> = vec![
+        ("BULK-1", transitions.clone()),
+        ("BULK-2", done.to_string()),
+        ("BULK-3", r#"{"transitions": []}"#.to_string()),
+    ]
+    .into_iter()
+    .map(|(key, body)| {
+        mock("GET", format!("/rest/api/2/issue/{}/transitions", key).as_str())
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    })
+    .collect();
+    let m1 = mock("POST", "/rest/api/2/issue/BULK-1/transitions")
+        .match_query(Matcher::UrlEncoded("notifyUsers".into(), "false".into()))
+        .match_body(Matcher::PartialJsonString(r#"{"transition": {"id": "31"}}"#.into()))
+        .with_status(204)
+        .create();
+    let m2 = mock("POST", "/rest/api/2/issue/BULK-2/transitions")
+        .match_query(Matcher::Any)
+        .match_body(Matcher::PartialJsonString(r#"{"transition": {"id": "71"}}"#.into()))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+    let options = BulkTransitionOptions {
+        notify_users: Some(false),
+        ..Default::default()
+    };
+
+    let results = Issue::transition_bulk(
+        &client,
+        &["BULK-1", "BULK-2", "BULK-3"],
+        &TransitionRef::Name("Done".to_string()),
+        &BTreeMap::new(),
+        options,
+    );
+
+    assert_eq!(results.len(), 3);
+    assert!(results["BULK-1"].is_ok());
+    assert!(results["BULK-2"].is_ok());
+    assert!(matches!(results["BULK-3"], Err(Error::TransitionNotFound { .. })));
+    m1.assert();
+    m2.assert();
+}
+
+#[test]
+fn test_transition_bulk_repeated_key() {
+    let transitions =
+        fs::read_to_string("tests/assets/v2/transitions.json").expect("Unable to read in JSON file");
+    let listed = mock("GET", "/rest/api/2/issue/TWICE-1/transitions")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions)
+        .expect(1)
+        .create();
+    let m = mock("POST", "/rest/api/2/issue/TWICE-1/transitions")
+        .match_query(Matcher::Any)
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let results = Issue::transition_bulk(
+        &client,
+        &["TWICE-1", "TWICE-1"],
+        &TransitionRef::Name("Done".to_string()),
+        &BTreeMap::new(),
+        BulkTransitionOptions::default(),
+    );
+
+    assert_eq!(results.len(), 1);
+    assert!(results["TWICE-1"].is_ok());
+    listed.assert();
+    m.assert();
+}
+
+#[test]
+fn test_transition_bulk_stop_on_error() {
+    let failing = mock("GET", "/rest/api/2/issue/STOP-1/transitions")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"transitions": []}"#)
+        .create();
+    let skipped = mock("GET", "/rest/api/2/issue/STOP-2/transitions")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"transitions": []}"#)
+        .expect(0)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+    let options = BulkTransitionOptions {
+        stop_on_error: true,
+        ..Default::default()
+    };
+
+    let results = Issue::transition_bulk(
+        &client,
+        &["STOP-1", "STOP-2", "STOP-3"],
+        &TransitionRef::Name("Done".to_string()),
+        &BTreeMap::new(),
+        options,
+    );
+
+    assert_eq!(results.len(), 3);
+    assert!(matches!(results["STOP-1"], Err(Error::TransitionNotFound { .. })));
+    assert!(matches!(results["STOP-2"], Err(Error::NotAttempted)));
+    assert!(matches!(results["STOP-3"], Err(Error::NotAttempted)));
+    failing.assert();
+    skipped.assert();
+}
+
+// ============================================================================
+// Tests (Sad Path)
+// ============================================================================
+#[test]
+fn test_transition_by_name_not_found() {
+    let transitions =
+        fs::read_to_string("tests/assets/v2/transitions.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/issue/TEST-3/transitions")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    match Issue::transition_by_name(&client, "TEST-3", "Reopen", BTreeMap::new()) {
+        Err(Error::TransitionNotFound { name, available }) => {
+            assert_eq!(name, "Reopen");
+            assert_eq!(available, vec!["Start Progress", "Done"]);
+        }
+        Err(e) => panic!("expected TransitionNotFound, got {:?}", e),
+        Ok(_) => panic!("expected TransitionNotFound"),
+    }
+}
+
+#[test]
+fn test_create_subtask_invalid() {
+    let _parent = mock_subtask_parent("TEST-32");
+    let m = mock("POST", "/rest/api/2/issue").expect(0).create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let not_a_subtask = NewIssue::builder()
+        .project("TEST")
+        .issue_type("Story")
+        .summary("Write tests")
+        .build()
+        .unwrap();
+    let other_project = NewIssue::builder()
+        .project("OTHER")
+        .issue_type("Sub-task")
+        .summary("Write tests")
+        .build()
+        .unwrap();
+
+    let not_in_project = NewIssue::builder()
+        .project("TEST")
+        .issue_type("Technical task")
+        .summary("Write tests")
+        .build()
+        .unwrap();
+
+    for issue in [not_a_subtask, other_project] {
+        match Issue::create_subtask(&client, "TEST-32", issue) {
+            Err(Error::InvalidSubtask(_)) => {}
+            Err(e) => panic!("expected InvalidSubtask, got {:?}", e),
+            Ok(_) => panic!("expected InvalidSubtask"),
+        }
+    }
+
+    // The type may exist on the instance, the project doesn't offer it
+    match Issue::create_subtask(&client, "TEST-32", not_in_project) {
+        Err(Error::InvalidIssueType { name, available }) => {
+            assert_eq!(name, "Technical task");
+            assert_eq!(available, vec!["Sub-task"]);
+        }
+        Err(e) => panic!("expected InvalidIssueType, got {:?}", e),
+        Ok(_) => panic!("expected InvalidIssueType"),
+    }
+    m.assert();
+}
+
+// ============================================================================
+// Private
+// ============================================================================
+/// Mocks the parent of a subtask along with the create meta of its project
+fn mock_subtask_parent(key: &str) -> Vec<mockito::Mock> {
+    let issue =
+        fs::read_to_string("tests/assets/v2/issue.json").expect("Unable to read in JSON file");
+    let meta = r#"{"projects": [{"id": "10000", "key": "TEST", "issuetypes": [
+        {"id": "10000", "name": "Sub-task", "subtask": true},
+        {"id": "10002", "name": "Story", "subtask": false}
+    ]}]}"#;
+
+    vec![
+        mock("GET", format!("/rest/api/2/issue/{}", key).as_str())
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(issue)
+            .create(),
+        mock("GET", "/rest/api/2/issue/createmeta")
+            .match_query(Matcher::UrlEncoded("projectKeys".into(), "TEST".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(meta)
+            .create(),
+    ]
+}
@@ -0,0 +1,37 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::Jql;
+use jirac::Client;
+use jirac::Credentials;
+use mockito::mock;
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_autocomplete_data() {
+    let result = fs::read_to_string("tests/assets/v2/jql_autocomplete.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/jql/autocompletedata")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let data = Jql::autocomplete_data(&client).unwrap().data;
+
+    assert!(data.field("team").is_some());
+    assert_eq!(data.jql_reserved_words.len(), 7);
+}
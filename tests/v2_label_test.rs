@@ -0,0 +1,40 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::Label;
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_suggestions() {
+    let result = fs::read_to_string("tests/assets/v2/label_suggestions.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/jql/autocompletedata/suggestions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("fieldName".into(), "labels".into()),
+            Matcher::UrlEncoded("fieldValue".into(), "back".into()),
+        ]))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let l = Label::suggestions(&client, "back").unwrap();
+
+    assert_eq!(l.data, vec!["backend", "backlog-grooming"]);
+}
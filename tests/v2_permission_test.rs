@@ -0,0 +1,108 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{MyPermission, PermissionHolder, PermissionScheme, PermissionSchemeExpand};
+use jirac::Client;
+use jirac::Credentials;
+use jirac::Error;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_my_permissions() {
+    let result = fs::read_to_string("tests/assets/v2/my_permissions.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/mypermissions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("projectKey".into(), "TEST".into()),
+            Matcher::UrlEncoded("permissions".into(), "CREATE_ISSUES,EDIT_ISSUES".into()),
+        ]))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let p = MyPermission::my(
+        &client,
+        Some("TEST"),
+        None,
+        Some(&["CREATE_ISSUES", "EDIT_ISSUES"]),
+    )
+    .unwrap();
+
+    assert!(p.data.permissions["CREATE_ISSUES"].have_permission);
+}
+
+#[test]
+fn test_permission_scheme_for_project() {
+    let result = fs::read_to_string("tests/assets/v2/permission_scheme.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/project/EX/permissionscheme")
+        .match_query(Matcher::UrlEncoded("expand".into(), "permissions".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let s = PermissionScheme::for_project(&client, "EX").unwrap().data;
+
+    assert_eq!(s.id, 10000);
+    assert!(s
+        .grants("BROWSE_PROJECTS")
+        .any(|g| g.holder == PermissionHolder::Group("jira-developers".into())));
+}
+
+#[test]
+fn test_permission_scheme_get() {
+    let result = fs::read_to_string("tests/assets/v2/permission_scheme.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/permissionscheme/10000")
+        .match_query(Matcher::UrlEncoded("expand".into(), "permissions,user".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let expand = vec![PermissionSchemeExpand::Permissions, PermissionSchemeExpand::User];
+    let s = PermissionScheme::get(&client, 10000, Some(expand)).unwrap().data;
+
+    assert_eq!(s.permissions.len(), 5);
+}
+
+#[test]
+fn test_permission_scheme_forbidden() {
+    let _m = mock("GET", "/rest/api/2/permissionscheme/10001")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorMessages": ["You do not have permission"], "errors": {}}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(matches!(PermissionScheme::get(&client, 10001, None), Err(Error::Forbidden)));
+}
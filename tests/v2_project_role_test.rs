@@ -0,0 +1,92 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{ProjectRole, RoleActorRef};
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_for_project() {
+    let result = fs::read_to_string("tests/assets/v2/project_roles.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/project/MKY/role")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let r = ProjectRole::for_project(&client, "MKY").unwrap();
+
+    assert_eq!(r.data.len(), 2);
+    assert!(r.data.contains_key("Developers"));
+}
+
+#[test]
+fn test_add_actors() {
+    let result = fs::read_to_string("tests/assets/v2/project_role.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("POST", "/rest/api/2/project/MKY/role/10360")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_body(Matcher::JsonString(
+            r#"{"user": ["fred"], "group": ["jira-developers"]}"#.to_string(),
+        ))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let actors = vec![
+        RoleActorRef::User("fred".to_string()),
+        RoleActorRef::Group("jira-developers".to_string()),
+    ];
+    let r = ProjectRole::add_actors(&client, "MKY", "10360", actors).unwrap();
+
+    assert_eq!(r.data.actors.len(), 2);
+}
+
+#[test]
+fn test_remove_actors() {
+    let u = mock("DELETE", "/rest/api/2/project/ABC/role/10360")
+        .with_status(204)
+        .match_query(Matcher::UrlEncoded("user".into(), "fred".into()))
+        .expect(1)
+        .create();
+    let g = mock("DELETE", "/rest/api/2/project/ABC/role/10360")
+        .with_status(204)
+        .match_query(Matcher::UrlEncoded("group".into(), "jira-developers".into()))
+        .expect(1)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let actors = vec![
+        RoleActorRef::User("fred".to_string()),
+        RoleActorRef::Group("jira-developers".to_string()),
+    ];
+
+    assert!(ProjectRole::remove_actors(&client, "ABC", "10360", actors).is_ok());
+
+    u.assert();
+    g.assert();
+}
@@ -0,0 +1,195 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{AssigneeType, NewProject, Project, ProjectUpdate, SecurityLevel};
+use jirac::Client;
+use jirac::Credentials;
+use jirac::Error;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_context_create_issue() {
+    let result =
+        fs::read_to_string("tests/assets/v2/createmeta.json").expect("Unable to read in JSON file");
+
+    let _meta = mock("GET", "/rest/api/2/issue/createmeta")
+        .match_query(Matcher::UrlEncoded("projectKeys".into(), "EX".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let create = mock("POST", "/rest/api/2/issue")
+        .match_body(Matcher::Json(serde_json::json!({"fields": {
+            "project": {"id": "10000"},
+            "issuetype": {"id": "3"},
+            "summary": "Write docs"
+        }})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id": "10010", "key": "EX-11", "self": "http://localhost:8080/rest/api/2/issue/10010"}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let ctx = Project::context(&client, "EX").unwrap();
+    let created = ctx.create_issue("Write docs", "Task").unwrap().data;
+
+    assert_eq!(created.key, "EX-11");
+    assert!(matches!(
+        ctx.create_issue("Plan", "Epic"),
+        Err(Error::InvalidIssueType { .. })
+    ));
+    create.assert();
+}
+
+#[test]
+fn test_context_component_id_fetched_once() {
+    let result =
+        fs::read_to_string("tests/assets/v2/createmeta.json").expect("Unable to read in JSON file");
+    let component =
+        fs::read_to_string("tests/assets/v2/component.json").expect("Unable to read in JSON file");
+
+    let _meta = mock("GET", "/rest/api/2/issue/createmeta")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let components = mock("GET", "/rest/api/2/project/EX/components")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!("[{}]", component))
+        .expect(1)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let ctx = Project::context(&client, "EX").unwrap();
+    let name = serde_json::from_str::<serde_json::Value>(&component).unwrap()["name"]
+        .as_str()
+        .unwrap()
+        .to_uppercase();
+
+    assert!(ctx.component_id(&name).is_ok());
+    assert!(matches!(
+        ctx.component_id("Nope"),
+        Err(Error::ComponentNotFound { .. })
+    ));
+    components.assert();
+}
+
+#[test]
+fn test_security_levels_for_project() {
+    let result = fs::read_to_string("tests/assets/v2/security_levels.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/project/EX/securitylevel")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let levels = SecurityLevel::for_project(&client, "EX").unwrap().data;
+
+    assert_eq!(levels.len(), 2);
+    assert_eq!(levels[0].name, "Reporter Only");
+}
+
+#[test]
+fn test_create_update_delete() {
+    let create = mock("POST", "/rest/api/2/project")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"key": "NEW", "leadAccountId": "5b10", "assigneeType": "UNASSIGNED"}"#.into(),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"self": "http://localhost:8080/rest/api/2/project/10042", "id": 10042, "key": "NEW"}"#)
+        .create();
+    let update = mock("PUT", "/rest/api/2/project/NEW")
+        .match_body(Matcher::Json(serde_json::json!({"name": "Renamed"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id": "10042", "key": "NEW", "name": "Renamed"}"#)
+        .create();
+    let delete = mock("DELETE", "/rest/api/2/project/NEW").with_status(204).create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let project = NewProject::builder()
+        .key("NEW")
+        .name("New")
+        .project_type_key("business")
+        .lead_account_id("5b10")
+        .assignee_type(AssigneeType::Unassigned)
+        .build()
+        .unwrap();
+    let created = Project::create(&client, project).unwrap().data;
+
+    assert_eq!(created.id, 10042);
+
+    let renamed = Project::update(&client, "NEW", ProjectUpdate::new().name("Renamed"))
+        .unwrap()
+        .data;
+
+    assert_eq!(renamed.name, "Renamed");
+    assert!(Project::delete(&client, "NEW").is_ok());
+    create.assert();
+    update.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_archive_requires_cloud() {
+    let server = fs::read_to_string("tests/assets/v2/server_info.json")
+        .expect("Unable to read in JSON file");
+
+    let _server = mock("GET", "/rest/api/2/serverInfo")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(&server)
+        .create();
+    let archive = mock("POST", "/rest/api/2/project/OLD/archive")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(matches!(
+        Project::archive(&client, "OLD"),
+        Err(Error::NotSupported(_))
+    ));
+
+    // The most recently created mock is matched first
+    let _cloud = mock("GET", "/rest/api/2/serverInfo")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(server.replace("\"Server\"", "\"Cloud\""))
+        .create();
+
+    assert!(Project::archive(&client, "OLD").is_ok());
+    archive.assert();
+}
@@ -0,0 +1,65 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{Properties, PropertyEntity};
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_issue_property_round_trip() {
+    let set = mock("PUT", "/rest/api/2/issue/EX-1/properties/sync")
+        .match_body(Matcher::Json(serde_json::json!({"revision": 4})))
+        .with_status(201)
+        .create();
+
+    let _get = mock("GET", "/rest/api/2/issue/EX-1/properties/sync")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"key": "sync", "value": {"revision": 4}}"#)
+        .create();
+
+    let _keys = mock("GET", "/rest/api/2/issue/EX-1/properties")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"keys": [{"self": "http://localhost:8080/rest/api/2/issue/EX-1/properties/sync", "key": "sync"}]}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let revision = serde_json::json!({"revision": 4});
+
+    Properties::set(&client, PropertyEntity::Issue, "EX-1", "sync", revision).unwrap();
+    let value = Properties::get(&client, PropertyEntity::Issue, "EX-1", "sync").unwrap().data;
+    let keys = Properties::keys(&client, PropertyEntity::Issue, "EX-1").unwrap().data;
+
+    assert_eq!(value["revision"], 4);
+    assert_eq!(keys, vec!["sync"]);
+    set.assert();
+}
+
+#[test]
+fn test_delete_user_property() {
+    let m = mock("DELETE", "/rest/api/2/user/properties/sync")
+        .match_query(Matcher::UrlEncoded("username".into(), "fred".into()))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    Properties::delete(&client, PropertyEntity::User, "fred", "sync").unwrap();
+
+    m.assert();
+}
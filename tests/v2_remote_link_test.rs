@@ -0,0 +1,118 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::RemoteLink;
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_list() {
+    let result =
+        fs::read_to_string("tests/assets/v2/remote_link.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/issue/MKY-1/remotelink")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let l = RemoteLink::list(&client, "MKY-1").unwrap();
+
+    assert_eq!(l.data.len(), 1);
+    assert_eq!(l.data[0].object.title, "TSTSUP-111");
+}
+
+#[test]
+fn test_create() {
+    let result = fs::read_to_string("tests/assets/v2/remote_link_created.json")
+        .expect("Unable to read in JSON file");
+
+    let _m = mock("POST", "/rest/api/2/issue/MKY-2/remotelink")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"globalId": "build-1", "object": {"url": "http://ci/1", "title": "Build 1"}}"#
+                .to_string(),
+        ))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let mut link = RemoteLink::new("http://ci/1", "Build 1");
+    link.global_id = "build-1".to_string();
+
+    let r = RemoteLink::create(&client, "MKY-2", &link).unwrap();
+
+    assert_eq!(r.data.id, 10000);
+}
+
+#[test]
+fn test_create_without_global_id() {
+    let result = fs::read_to_string("tests/assets/v2/remote_link_created.json")
+        .expect("Unable to read in JSON file");
+
+    // Only the object is sent, an empty globalId would make JIRA update the
+    // last link created without one
+    let m = mock("POST", "/rest/api/2/issue/MKY-3/remotelink")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .match_body(Matcher::Json(serde_json::json!({
+            "object": {
+                "url": "http://ci/2",
+                "title": "Build 2",
+                "summary": "",
+                "icon": null,
+                "status": null
+            }
+        })))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let link = RemoteLink::new("http://ci/2", "Build 2");
+
+    assert!(RemoteLink::create(&client, "MKY-3", &link).is_ok());
+    m.assert();
+}
+
+#[test]
+fn test_delete_by_global_id() {
+    let _m = mock("DELETE", "/rest/api/2/issue/MKY-3/remotelink")
+        .with_status(204)
+        .match_query(Matcher::UrlEncoded(
+            "globalId".into(),
+            "system=http://www.mycompany.com/support&id=1".into(),
+        ))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(RemoteLink::delete_by_global_id(
+        &client,
+        "MKY-3",
+        "system=http://www.mycompany.com/support&id=1"
+    )
+    .is_ok());
+}
@@ -0,0 +1,272 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{Fields, Pagination, Search};
+use jirac::Client;
+use jirac::Credentials;
+use jirac::Error;
+use mockito::{mock, Matcher};
+use serde_json::Value;
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+/// Builds a page of search results from the fixture, one issue per key
+fn page(start_at: i64, total: i64, keys: &[&str]) -> String {
+    let result =
+        fs::read_to_string("tests/assets/v2/search.json").expect("Unable to read in JSON file");
+    let mut v: Value = serde_json::from_str(&result).unwrap();
+    let issue = v["issues"][0].clone();
+
+    v["startAt"] = start_at.into();
+    v["total"] = total.into();
+    v["issues"] = keys
+        .iter()
+        .map(|k| {
+            let mut i = issue.clone();
+            i["key"] = (*k).into();
+            i
+        })
+        .collect();
+
+    v.to_string()
+}
+
+#[test]
+fn test_jql_all() {
+    let _first = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = ALL", "startAt": 0}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(0, 3, &["ALL-1", "ALL-2"]))
+        .create();
+
+    let _second = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = ALL", "startAt": 2}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(2, 3, &["ALL-3"]))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let issues = Search::jql_all(&client, "project = ALL", None).unwrap();
+    let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+
+    assert_eq!(keys, vec!["ALL-1", "ALL-2", "ALL-3"]);
+}
+
+#[test]
+fn test_jql_all_with_idempotency_key() {
+    let first = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = KEY", "startAt": 0}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(0, 3, &["KEY-1", "KEY-2"]))
+        .expect(1)
+        .create();
+
+    let second = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = KEY", "startAt": 2}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(2, 3, &["KEY-3"]))
+        .expect(1)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds).idempotency_key("import-1");
+
+    // Search pages are reads, the key must not replay the first page
+    let issues = Search::jql_all(&client, "project = KEY", None).unwrap();
+    let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+
+    assert_eq!(keys, vec!["KEY-1", "KEY-2", "KEY-3"]);
+    first.assert();
+    second.assert();
+}
+
+#[test]
+fn test_jql_all_record_and_replay() {
+    let dir = std::env::temp_dir().join(format!("jirac-search-fixtures-{}", std::process::id()));
+    let _first = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = REC", "startAt": 0}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(0, 3, &["REC-1", "REC-2"]))
+        .create();
+
+    let _second = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = REC", "startAt": 2}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(2, 3, &["REC-3"]))
+        .create();
+
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let recorder = Client::builder(mockito::server_url(), creds.clone())
+        .record_fixtures(&dir)
+        .build()
+        .unwrap();
+
+    Search::jql_all(&recorder, "project = REC", None).unwrap();
+
+    // Each page is a POST to the same URL, only the body tells them apart
+    let replayer = Client::builder("http://127.0.0.1:1", creds)
+        .replay_fixtures(&dir)
+        .build()
+        .unwrap();
+    let issues = Search::jql_all(&replayer, "project = REC", None).unwrap();
+    let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+
+    assert_eq!(keys, vec!["REC-1", "REC-2", "REC-3"]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_jql_all_aborts_on_error() {
+    let _first = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = ERR", "startAt": 0}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(0, 3, &["ERR-1", "ERR-2"]))
+        .create();
+
+    let _second = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = ERR", "startAt": 2}"#.into()))
+        .with_status(401)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    assert!(Search::jql_all(&client, "project = ERR", None).is_err());
+}
+
+#[test]
+fn test_jql_clamps_page_size() {
+    let m = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"jql": "project = BIG", "maxResults": 1000}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(0, 1, &["BIG-1"]))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let results = Search::jql(&client, "project = BIG", Some(Pagination::new(0, 5000)))
+        .unwrap()
+        .data;
+
+    // The fixture reports the 50 JIRA actually used
+    assert!(results.pagination.is_clamped(1000));
+    m.assert();
+}
+
+#[test]
+fn test_jql_fields() {
+    let m = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"jql": "project = SLIM", "fields": ["*all", "-comment", "-worklog"]}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(0, 1, &["SLIM-1"]))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let fields = Fields::exclude(&["comment", "worklog"]);
+
+    assert!(Search::jql_fields(&client, "project = SLIM", &fields, None).is_ok());
+    m.assert();
+}
+
+#[test]
+fn test_jql_rejects_empty_page() {
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let result = Search::jql(&client, "project = ZERO", Some(Pagination::new(0, 0)));
+
+    assert!(matches!(result, Err(Error::InvalidPagination(_))));
+}
+
+#[test]
+fn test_jql_export_writes_lines() {
+    let _first = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = EXP", "startAt": 0}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(0, 3, &["EXP-1", "EXP-2"]))
+        .create();
+
+    let _second = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = EXP", "startAt": 2}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(2, 3, &["EXP-3"]))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let mut out = Vec::new();
+    let (written, result) = Search::jql_export(&client, "project = EXP", &mut out);
+
+    result.unwrap();
+    assert_eq!(written, 3);
+
+    let keys: Vec<String> = String::from_utf8(out)
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str::<Value>(l).unwrap()["key"].as_str().unwrap().to_string())
+        .collect();
+
+    assert_eq!(keys, vec!["EXP-1", "EXP-2", "EXP-3"]);
+}
+
+#[test]
+fn test_jql_export_keeps_partial_output() {
+    let _first = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = PART", "startAt": 0}"#.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(0, 3, &["PART-1", "PART-2"]))
+        .create();
+
+    let _second = mock("POST", "/rest/api/2/search")
+        .match_body(Matcher::PartialJsonString(r#"{"jql": "project = PART", "startAt": 2}"#.into()))
+        .with_status(401)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let mut out = Vec::new();
+    let (written, result) = Search::jql_export(&client, "project = PART", &mut out);
+
+    assert!(matches!(result, Err(Error::Unauthorized)));
+    assert_eq!(written, 2);
+    assert_eq!(String::from_utf8(out).unwrap().lines().count(), 2);
+}
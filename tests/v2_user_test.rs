@@ -6,9 +6,10 @@ extern crate jirac;
 // ============================================================================
 // Use
 // ============================================================================
-use jirac::v2::{User, UserExpand};
+use jirac::v2::{AssignableTo, User, UserExpand};
 use jirac::Client;
 use jirac::Credentials;
+use jirac::Error;
 use jirac::Resp;
 use mockito::{mock, Matcher};
 use std::fs;
@@ -43,3 +44,227 @@ fn test_get_user() {
 
     assert_eq!(user.name, "fred");
 }
+
+#[test]
+fn test_search_encodes_username() {
+    let _m = mock("GET", "/rest/api/2/user/search")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("username".into(), "fred+jira@example.com".into()),
+            Matcher::UrlEncoded("startAt".into(), "0".into()),
+        ]))
+        .with_body("[]")
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let Resp {
+        data: users,
+        headers: _h,
+    } = User::search(&client, "fred+jira@example.com", None, None).unwrap();
+
+    assert!(users.is_empty());
+}
+
+#[test]
+fn test_get_user_from_account_id() {
+    let result =
+        fs::read_to_string("tests/assets/v2/user_cloud.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::UrlEncoded(
+            "accountId".into(),
+            "5b10a2844c20165700ede21g".into(),
+        ))
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let Resp {
+        data: user,
+        headers: _h,
+    } = User::from_account_id(&client, "5b10a2844c20165700ede21g", vec![]).unwrap();
+
+    assert_eq!(user.account_id, "5b10a2844c20165700ede21g");
+    assert_eq!(user.display_name, "Mia Krystof");
+}
+
+#[test]
+fn test_search_cloud() {
+    let result =
+        fs::read_to_string("tests/assets/v2/user_cloud.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/user/search")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::UrlEncoded("query".into(), "mia@example.com".into()))
+        .with_body(format!("[{}]", result))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let Resp {
+        data: users,
+        headers: _h,
+    } = User::search_cloud(&client, "mia@example.com", None).unwrap();
+
+    assert_eq!(users[0].account_id, "5b10a2844c20165700ede21g");
+}
+
+#[test]
+fn test_assignable() {
+    let result =
+        fs::read_to_string("tests/assets/v2/user.json").expect("Unable to read in JSON file");
+
+    let m = mock("GET", "/rest/api/2/user/assignable/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("issueKey".into(), "EX-1".into()),
+            Matcher::UrlEncoded("username".into(), "fr".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!("[{}]", result))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let users = User::assignable(&client, AssignableTo::Issue("EX-1".to_string()), "fr", None)
+        .unwrap()
+        .data;
+
+    assert_eq!(users.len(), 1);
+    m.assert();
+}
+
+#[test]
+fn test_bulk_keeps_order_and_missing_users() {
+    let result =
+        fs::read_to_string("tests/assets/v2/user.json").expect("Unable to read in JSON file");
+
+    let _m1 = mock("GET", "/rest/api/2/user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::UrlEncoded("username".into(), "jane".into()))
+        .with_body(result)
+        .create();
+
+    let _m2 = mock("GET", "/rest/api/2/user")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::UrlEncoded("username".into(), "ghost".into()))
+        .with_body(r#"{"errorMessages": ["The user named 'ghost' does not exist"]}"#)
+        .create();
+    let _m3 = mock("GET", "/rest/api/2/user")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::UrlEncoded("key".into(), "ghost".into()))
+        .with_body(r#"{"errorMessages": ["The user with key 'ghost' does not exist"]}"#)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let users = User::bulk(&client, &["ghost", "jane"]);
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].0, "ghost");
+    assert!(users[0].1.as_ref().unwrap().is_none());
+    assert_eq!(users[1].0, "jane");
+    assert_eq!(users[1].1.as_ref().unwrap().as_ref().unwrap().name, "fred");
+}
+
+#[test]
+fn test_bulk_falls_back_to_keys_and_keeps_going() {
+    let result =
+        fs::read_to_string("tests/assets/v2/user.json").expect("Unable to read in JSON file");
+
+    let _by_username = mock("GET", "/rest/api/2/user")
+        .with_status(404)
+        .match_query(Matcher::UrlEncoded("username".into(), "JIRAUSER10100".into()))
+        .create();
+    let _by_key = mock("GET", "/rest/api/2/user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::UrlEncoded("key".into(), "JIRAUSER10100".into()))
+        .with_body(result)
+        .create();
+    let _broken = mock("GET", "/rest/api/2/user")
+        .with_status(500)
+        .match_query(Matcher::UrlEncoded("username".into(), "broken".into()))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let users = User::bulk(&client, &["broken", "JIRAUSER10100"]);
+
+    assert!(matches!(users[0].1, Err(Error::Fault { .. })));
+    assert_eq!(users[1].1.as_ref().unwrap().as_ref().unwrap().name, "fred");
+}
+
+#[test]
+fn test_bulk_account_ids() {
+    let result =
+        fs::read_to_string("tests/assets/v2/user_cloud.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/user/bulk")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("accountId".into(), "5b10a2844c20165700ede21g".into()),
+            Matcher::UrlEncoded("accountId".into(), "missing".into()),
+            Matcher::UrlEncoded("maxResults".into(), "2".into()),
+        ]))
+        .with_body(format!(r#"{{"isLast": true, "values": [{}]}}"#, result))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let users =
+        User::bulk_account_ids(&client, &["missing", "5b10a2844c20165700ede21g"]).unwrap();
+
+    assert_eq!(users[0].0, "missing");
+    assert!(users[0].1.is_none());
+    assert_eq!(users[1].1.as_ref().unwrap().display_name, "Mia Krystof");
+}
+
+#[test]
+fn test_bulk_account_ids_repeated() {
+    let result =
+        fs::read_to_string("tests/assets/v2/user_cloud.json").expect("Unable to read in JSON file");
+
+    let _m = mock("GET", "/rest/api/2/user/bulk")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("accountId".into(), "5b10a2844c20165700ede21g".into()),
+            Matcher::UrlEncoded("maxResults".into(), "2".into()),
+        ]))
+        .with_body(format!(r#"{{"isLast": true, "values": [{}]}}"#, result))
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let id = "5b10a2844c20165700ede21g";
+    let users = User::bulk_account_ids(&client, &[id, id]).unwrap();
+
+    assert!(users.iter().all(|(_, u)| u.as_ref().unwrap().display_name == "Mia Krystof"));
+}
@@ -9,7 +9,7 @@ extern crate jirac;
 use jirac::v2::Version;
 use jirac::Client;
 use jirac::Credentials;
-use mockito::mock;
+use mockito::{mock, Matcher};
 use std::fs;
 
 // ============================================================================
@@ -55,3 +55,38 @@ fn test_create() {
 
     assert_eq!(c.data.project_id, 10000);
 }
+
+#[test]
+fn test_delete_self() {
+    let result =
+        fs::read_to_string("tests/assets/v2/version.json").expect("Unable to read in JSON file");
+    let version: Version = serde_json::from_str(&result).unwrap();
+
+    let m = mock("DELETE", "/rest/api/2/version/10000")
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    version.delete_self(&client).unwrap();
+
+    m.assert();
+}
+
+#[test]
+fn test_delete_and_replace() {
+    let m = mock("POST", "/rest/api/2/version/10001/removeAndSwap")
+        .match_body(Matcher::Json(serde_json::json!({"moveFixIssuesTo": "10002"})))
+        .with_status(204)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    Version::delete_and_replace(&client, "10001", Some("10002".to_string()), None).unwrap();
+
+    m.assert();
+}
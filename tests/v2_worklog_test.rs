@@ -0,0 +1,46 @@
+// ============================================================================
+// External Crates
+// ============================================================================
+extern crate jirac;
+
+// ============================================================================
+// Use
+// ============================================================================
+use jirac::v2::{AdjustEstimate, NewWorklog, Worklog, WorklogOptions};
+use jirac::Client;
+use jirac::Credentials;
+use mockito::{mock, Matcher};
+use std::fs;
+
+// ============================================================================
+// Tests
+// ============================================================================
+#[test]
+fn test_add_with_new_estimate() {
+    let result =
+        fs::read_to_string("tests/assets/v2/worklog.json").expect("Unable to read in JSON file");
+
+    let m = mock("POST", "/rest/api/2/issue/TEST-1/worklog")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("adjustEstimate".into(), "new".into()),
+            Matcher::UrlEncoded("newEstimate".into(), "1d".into()),
+        ]))
+        .match_body(Matcher::Json(serde_json::json!({"timeSpent": "2d 4h", "comment": "Fixed"})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(result)
+        .create();
+
+    let url = &mockito::server_url();
+    let creds = Credentials::new_basic("test", "test").unwrap();
+    let client = Client::new(url, creds);
+
+    let mut worklog = NewWorklog::new("2d 4h");
+    worklog.comment = "Fixed".to_string();
+
+    let opts = WorklogOptions::new(AdjustEstimate::New("1d".to_string()));
+    let w = Worklog::add(&client, "TEST-1", worklog, Some(opts)).unwrap().data;
+
+    assert_eq!(w.time_spent_seconds, Some(72000));
+    m.assert();
+}